@@ -0,0 +1,102 @@
+//! # Bench
+//! Timings recorded by benchmark tests, reported by their benchmark module
+
+use std::{sync::Mutex, time::Duration};
+use crate::Namepath;
+#[cfg(feature = "json")]
+use crate::NamepathTrait;
+
+/// The timings of a single benchmark, recorded by `Test::bench()`.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct BenchResult {
+    pub(crate) namepath: Namepath,
+    pub(crate) iterations: usize,
+    pub(crate) mean: Duration,
+    pub(crate) min: Duration,
+    pub(crate) max: Duration,
+}
+
+impl BenchResult {
+    /// Summarizes the duration of each iteration. There must be at least one.
+    pub(crate) fn new(namepath: Namepath, timings: &[Duration]) -> Self {
+        assert!(!timings.is_empty(), "Benchmark requires at least one iteration");
+        Self {
+            namepath,
+            iterations: timings.len(),
+            mean: timings.iter().sum::<Duration>() / timings.len() as u32,
+            min: *timings.iter().min().unwrap(),
+            max: *timings.iter().max().unwrap(),
+        }
+    }
+
+    /// The namepath of the test that ran the benchmark.
+    pub fn namepath(&self) -> &Namepath {
+        &self.namepath
+    }
+
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    pub fn mean(&self) -> Duration {
+        self.mean
+    }
+
+    pub fn min(&self) -> Duration {
+        self.min
+    }
+
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+}
+
+/// A summary of every benchmark recorded by a benchmark module, in the order that they were recorded.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct BenchReport {
+    pub(crate) results: Vec<BenchResult>,
+}
+
+impl BenchReport {
+    pub fn results(&self) -> &[BenchResult] {
+        &self.results
+    }
+
+    /// Emits the report as a JSON array, with durations in nanoseconds.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> String {
+        let results: Vec<serde_json::Value> = self.results.iter()
+            .map(|result| serde_json::json!({
+                "namepath": result.namepath.path(),
+                "iterations": result.iterations,
+                "mean_ns": result.mean.as_nanos() as u64,
+                "min_ns": result.min.as_nanos() as u64,
+                "max_ns": result.max.as_nanos() as u64,
+            }))
+            .collect();
+
+        serde_json::Value::Array(results).to_string()
+    }
+}
+
+/// The benchmarks recorded by a module. Shared by its tests, which only hold a reference to it.
+#[derive(Debug, Default)]
+pub(crate) struct BenchResults(Mutex<Vec<BenchResult>>);
+
+impl BenchResults {
+    pub(crate) fn record(&self, result: BenchResult) {
+        self.0.lock().unwrap().push(result);
+    }
+
+    pub(crate) fn report(&self) -> BenchReport {
+        BenchReport { results: self.0.lock().unwrap().clone() }
+    }
+}
+
+impl PartialEq for BenchResults {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other) || *self.0.lock().unwrap() == *other.0.lock().unwrap()
+    }
+}
+
+impl Eq for BenchResults {}
@@ -1,14 +1,16 @@
 use std::{collections::HashMap, path::{PathBuf, Path}};
 use anyhow::Context;
 
-use crate::{Testable, Module, TestBuilder, Namepath };
+use crate::{Testable, Module, TestBuilder, Namepath, NamepathTrait};
 
 pub struct Group<'module,'func> {
+    pub(crate) is_static: bool,
     pub(crate) module: &'module Module,
     pub(crate) namepath: Namepath,
     pub(crate) temp_dir: Option<PathBuf>,
     pub(crate) fixture_dir: Option<PathBuf>,
     pub(crate) imported_fixture_dirs: Option<HashMap<Namepath, PathBuf>>,
+    pub(crate) imported_fixture_paths: Option<HashMap<String, PathBuf>>,
     pub(crate) teardown_func: Option<Box<dyn FnOnce(&mut Group) + Sync + Send + 'func>>,
 }
 
@@ -32,6 +34,26 @@ impl<'module,'func> Group<'module,'func> {
         &self.namepath
     }
 
+    /// Shorthand for `namepath().path()`.
+    pub fn namepath_str(&self) -> &str {
+        self.namepath.path()
+    }
+
+    /// Whether this group was built as static, with its teardown handled by a process exit hook.
+    pub fn is_static(&self) -> bool {
+        self.is_static
+    }
+
+    /// Whether both groups represent the same group under test, by namepath and the use-case of their modules.
+    pub fn same_as(&self, other: &Group) -> bool {
+        self.namepath == other.namepath && self.module.use_case == other.module.use_case
+    }
+
+    /// The namepaths of this group's lineage, in order: [ module, group ]
+    pub fn ancestry(&self) -> Vec<&Namepath> {
+        vec![self.module.namepath(), &self.namepath]
+    }
+
     pub fn temp_dir(&self) -> &Path {
         &self.temp_dir.as_ref().context("Group `temp dir` is not configured").unwrap()
     }
@@ -44,12 +66,30 @@ impl<'module,'func> Group<'module,'func> {
         }
 
         self.module.try_imported_fixture_dir(namepath)
-            .context("Group: `imported fixture dirs` is not configured")
+            .with_context(|| format!("Group: {}",
+                crate::imported_fixture_dir_not_found(namepath, self.imported_fixture_dirs.as_ref())))
+    }
+
+    pub(crate) fn try_imported_fixture_path(&self, key: &str) -> anyhow::Result<&Path> {
+        if let Some(imported_fixture_paths) = self.imported_fixture_paths.as_ref() {
+            if let Some(dir) = imported_fixture_paths.get(key) {
+                return Ok(dir.as_path());
+            }
+        }
+
+        self.module.try_imported_fixture_path(key)
+            .context("Group: `imported fixture paths` is not configured")
     }
 
     fn teardown(&mut self) {
         if let Some(teardown_func) = self.teardown_func.take() {
-            teardown_func(self);
+            if self.module.teardown_catch_panics {
+                let module = self.module;
+                let namepath = self.namepath.clone();
+                module.teardown_panics.catch(&namepath, || teardown_func(self));
+            } else {
+                teardown_func(self);
+            }
         }
 
         if let Some(dir) = self.temp_dir.take() {
@@ -68,6 +108,10 @@ impl<'module, 'func> Testable for Group<'module, 'func> {
     fn imported_fixture_dir(&self, namepath: &Namepath) -> &Path {
         self.try_imported_fixture_dir(namepath).unwrap()
     }
+
+    fn imported_fixture_path(&self, key: &str) -> &Path {
+        self.try_imported_fixture_path(key).unwrap()
+    }
 }
 
 impl<'module,'func> Drop for Group<'module,'func> {
@@ -85,6 +129,7 @@ pub struct GroupBuilder<'module,'func> {
     pub(crate) using_fixture_dir: bool,
     pub(crate) inherit_fixture_dir: bool,
     pub(crate) imported_fixture_dirs: Option<HashMap<Namepath, PathBuf>>,
+    pub(crate) imported_fixture_paths: Option<HashMap<String, PathBuf>>,
     pub(crate) setup_func: Option<Box<dyn FnOnce(&mut Group) + 'func>>,
     pub(crate) teardown_func: Option<Box<dyn FnOnce(&mut Group) + Sync + Send + 'func>>,
     pub(crate) static_teardown_func: Option<Box<extern fn()>>,
@@ -92,8 +137,9 @@ pub struct GroupBuilder<'module,'func> {
 
 impl<'module,'func> GroupBuilder<'module,'func> {
     pub(crate) fn new(module: &'module Module, name: &str, is_static: bool) -> Self {
-        debug_assert!(!name.contains(':') && !name.contains('/') && !name.contains('.'),
-            "Group name should be a single non-delimited token.");
+        crate::namepath::validate_name_token(name)
+            .context("Group name should be a single non-delimited token")
+            .unwrap();
 
         Self {
             is_static,
@@ -104,6 +150,7 @@ impl<'module,'func> GroupBuilder<'module,'func> {
             using_fixture_dir: false,
             inherit_fixture_dir: false,
             imported_fixture_dirs: None,
+            imported_fixture_paths: None,
             setup_func: None,
             teardown_func: None,
             static_teardown_func: None,
@@ -122,7 +169,7 @@ impl<'module,'func> GroupBuilder<'module,'func> {
         };
 
         let fixture_dir = if self.using_fixture_dir {
-            Some(crate::build_fixture_dir(&namepath, self.module.use_case))
+            Some(crate::build_fixture_dir(&namepath, self.module.fixture_use_case))
         } else if self.inherit_fixture_dir {
             Some(self.module.fixture_dir().to_owned())
         } else {
@@ -130,13 +177,16 @@ impl<'module,'func> GroupBuilder<'module,'func> {
         };
 
         let imported_fixture_dirs = self.imported_fixture_dirs;
+        let imported_fixture_paths = self.imported_fixture_paths;
 
         let mut group = Group {
+            is_static: self.is_static,
             module: self.module,
             namepath: namepath,
             temp_dir,
             fixture_dir,
             imported_fixture_dirs,
+            imported_fixture_paths,
             teardown_func: self.teardown_func
         };
 
@@ -178,8 +228,10 @@ impl<'module,'func> GroupBuilder<'module,'func> {
         self
     }
 
+    /// Imports the fixture directory of another Module, Group, or Test.
+    /// Each test built from this group starts with its own copy of these imports, rather than a reference to the group's.
     pub fn import_fixture_dir(mut self, namepath: &Namepath) -> Self {
-        let dir = crate::build_fixture_dir(&namepath, self.module.use_case);
+        let dir = crate::build_fixture_dir(&namepath, self.module.fixture_use_case);
         let dir = dir.canonicalize()
             .context(format!("Imported fixture dir does not exist: {}", &dir.to_str().unwrap()))
             .unwrap();
@@ -194,6 +246,21 @@ impl<'module,'func> GroupBuilder<'module,'func> {
         self
     }
 
+    /// Imports an arbitrary existing directory under `key`, bypassing namepath resolution.
+    /// Retrievable with [imported_fixture_path()](Testable::imported_fixture_path).
+    pub fn import_fixture_path(mut self, key: &str, path: impl AsRef<Path>) -> Self {
+        let dir = crate::build_imported_fixture_path(path.as_ref());
+
+        if self.imported_fixture_paths.is_none() {
+            self.imported_fixture_paths = Some(HashMap::new());
+        }
+
+        self.imported_fixture_paths.as_mut().expect("Option should exist")
+            .insert(key.to_owned(), dir);
+
+        self
+    }
+
     pub fn inherit_fixture_dir(mut self) -> Self {
         assert!(!self.using_fixture_dir);
         self.inherit_fixture_dir = true;
@@ -237,7 +304,7 @@ mod tests {
 
     #[test] #[named]
     fn test_module() {
-        let testgroup = MODULE_BASIC.local_group(function_name!()).build();
+        let testgroup = MODULE_BASIC.nonstatic_group(function_name!()).build();
         assert_eq!(&*MODULE_BASIC, testgroup.module(),
             "Parent module should be retrievable");
     }
@@ -245,7 +312,7 @@ mod tests {
     #[test]
     #[named]
     fn test_name() {
-        let testgroup = MODULE_BASIC.local_group(function_name!()).build();
+        let testgroup = MODULE_BASIC.nonstatic_group(function_name!()).build();
         assert_eq!(function_name!(), testgroup.name(),
             "Name should be awesome");
     }
@@ -260,23 +327,47 @@ mod tests {
     #[test] #[named]
     fn test_namepath() {
         let expected_namepath = concat!(module_path!(), "::", function_name!());
-        let testgroup = MODULE_BASIC.local_group(function_name!()).build();
+        let testgroup = MODULE_BASIC.nonstatic_group(function_name!()).build();
 
         assert_eq!(expected_namepath, testgroup.namepath().path(),
             "Group namepath should reflect: `Group.module().namepath()` / `Group.name()`");
+        assert_eq!(testgroup.namepath().path(), testgroup.namepath_str(),
+            "Group namepath_str() should be shorthand for `namepath().path()`.");
+    }
+
+    // Group ancestry should be its Module's namepath followed by its own.
+    #[test] #[named]
+    fn test_ancestry() {
+        let testgroup = MODULE_BASIC.nonstatic_group(function_name!()).build();
+
+        assert_eq!(vec![MODULE_BASIC.namepath(), testgroup.namepath()], testgroup.ancestry(),
+            "Group ancestry should be its Module's namepath followed by its own.");
+    }
+
+    // Groups built with the same name under the same Module should be the same.
+    #[test] #[named]
+    fn test_same_as() {
+        let group_a = MODULE_BASIC.nonstatic_group(function_name!()).build();
+        let group_b = MODULE_BASIC.nonstatic_group(function_name!()).build();
+        let other = MODULE_BASIC.nonstatic_group("other").build();
+
+        assert!(group_a.same_as(&group_b),
+            "Groups built with the same name under the same Module should be the same.");
+        assert!(!group_a.same_as(&other),
+            "Groups built with different names should not be the same.");
     }
 
     // Group not configured with a temp dir should panic when attempting to access it 
     #[test] #[should_panic] #[named]
     fn test_temp_dir_unconfigured_access() {
-        let testgroup = MODULE_BASIC.local_group(function_name!()).build();
+        let testgroup = MODULE_BASIC.nonstatic_group(function_name!()).build();
         testgroup.temp_dir();  // should panic
     }
 
     // Group should not allow configuration with `using_temp_dir()` if its parent Module is not using a temp dir.
     #[test] #[should_panic] #[named]
     fn test_temp_dir_using_unconfigured_module() {
-        MODULE_BASIC.local_group(function_name!())
+        MODULE_BASIC.nonstatic_group(function_name!())
             .using_temp_dir()  // should panic
             .build();
     }
@@ -284,7 +375,7 @@ mod tests {
     // Group should not allow configuration with `inherit_temp_dir()` if its parent Module is not using a temp dir.
     #[test] #[should_panic] #[named]
     fn test_temp_dir_inherited_unconfigured_module() {
-        MODULE_BASIC.local_group(function_name!())
+        MODULE_BASIC.nonstatic_group(function_name!())
             .inherit_temp_dir()  // should panic
             .build();
     }
@@ -293,7 +384,7 @@ mod tests {
     // Group configured with `using_temp_dir()` should create the directory on construction if it does not exist.
     #[test] #[named]
     fn test_temp_dir_using() {
-        let testgroup = MODULE_WITH_DIRS.local_group(function_name!())
+        let testgroup = MODULE_WITH_DIRS.nonstatic_group(function_name!())
             .using_temp_dir()
             .build();
 
@@ -307,7 +398,7 @@ mod tests {
     // Group configured to `inherit_temp_dir()` should have the same temp path as its parent.
     #[test] #[named]
     fn test_temp_dir_inherited() {
-        let testgroup = MODULE_WITH_DIRS.local_group(function_name!())
+        let testgroup = MODULE_WITH_DIRS.nonstatic_group(function_name!())
             .inherit_temp_dir()
             .build();
 
@@ -318,14 +409,14 @@ mod tests {
     // Group not configured with a fixture dir should panic when attempting to access it 
     #[test] #[should_panic] #[named]
     fn test_fixture_dir_unconfigured_access() {
-        let testgroup = MODULE_WITH_DIRS.local_group(function_name!()).build();
+        let testgroup = MODULE_WITH_DIRS.nonstatic_group(function_name!()).build();
         testgroup.fixture_dir(); // should panic
     }
 
     // Group should not allow configuration with `using_fixture_dir()` if its parent Module is not using a fixture dir.
     #[test] #[should_panic] #[named]
     fn test_fixture_dir_using_unconfigured_module() {
-        MODULE_BASIC.local_group(function_name!())
+        MODULE_BASIC.nonstatic_group(function_name!())
             .using_fixture_dir()  // should panic
             .build();
     }
@@ -333,7 +424,7 @@ mod tests {
     // Group should not allow configuration with `inherit_fixture_dir()` if its parent Module is not using a fixture dir.
     #[test] #[should_panic] #[named]
     fn test_fixture_dir_inherited_unconfigured_module() {
-        MODULE_BASIC.local_group(function_name!())
+        MODULE_BASIC.nonstatic_group(function_name!())
             .inherit_fixture_dir()  // should panic
             .build();
     }
@@ -343,7 +434,7 @@ mod tests {
     // Fixture path should exist for Group configured with `using_fixture_dir()`
      #[test] #[named]
     fn test_fixture_dir_using() {
-        let testgroup = MODULE_WITH_DIRS.local_group(function_name!())
+        let testgroup = MODULE_WITH_DIRS.nonstatic_group(function_name!())
             .using_fixture_dir()
             .build();
 
@@ -358,7 +449,7 @@ mod tests {
     // Fixture path should exist for Group configured with `inherit_fixture_dir()`
     #[test] #[named]
     fn test_fixture_dir_inherited() {
-        let testgroup = MODULE_WITH_DIRS.local_group(function_name!())
+        let testgroup = MODULE_WITH_DIRS.nonstatic_group(function_name!())
             .inherit_fixture_dir()
             .build();
 
@@ -371,7 +462,7 @@ mod tests {
 
     #[test] #[named]
     fn test_import_fixture_dir() {
-        let testgroup = MODULE_BASIC.local_group(function_name!())
+        let testgroup = MODULE_BASIC.nonstatic_group(function_name!())
             .import_fixture_dir(&MODULE_WITH_DIRS.namepath())
             .build();
 
@@ -381,7 +472,7 @@ mod tests {
 
     #[test] #[named] #[should_panic]
     fn test_import_fixture_dir_fail() {
-        let testgroup = MODULE_BASIC.local_group(function_name!())
+        let testgroup = MODULE_BASIC.nonstatic_group(function_name!())
             .build();
 
         testgroup.imported_fixture_dir(MODULE_WITH_DIRS.namepath()); // should panic
@@ -406,7 +497,7 @@ mod tests {
             .import_fixture_dir(&namepath)
             .nonstatic()
             .build();
-        let test_group = test_module.local_group(function_name!())
+        let test_group = test_module.nonstatic_group(function_name!())
             .build();
 
         assert_eq!(expected_unit_module_fixture_dir(), test_group.imported_fixture_dir(&namepath),
@@ -425,7 +516,7 @@ mod tests {
     // Group setup function should be ran on construction.
     #[test] #[named]
     fn test_setup_function() {
-        let _testgroup = MODULE_BASIC.local_group(function_name!())
+        let _testgroup = MODULE_BASIC.nonstatic_group(function_name!())
             .setup(setup_func)
             .build();
 
@@ -439,7 +530,7 @@ mod tests {
     #[test] #[named]
     fn test_setup_closure() {
         let mut setup_closure_called = false;
-        MODULE_BASIC.local_group(function_name!())
+        MODULE_BASIC.nonstatic_group(function_name!())
             .setup(|_| {
                 setup_closure_called = true;
             })
@@ -461,7 +552,7 @@ mod tests {
     #[test] #[named]
     fn test_teardown_function() {
         {
-            MODULE_BASIC.local_group(function_name!())
+            MODULE_BASIC.nonstatic_group(function_name!())
             .teardown(teardown_func)
             .build();
         }
@@ -477,7 +568,7 @@ mod tests {
     fn test_teardown_closure() {
         let mut teardown_closure_called = false;
         {
-            MODULE_BASIC.local_group(function_name!())
+            MODULE_BASIC.nonstatic_group(function_name!())
                 .teardown(|_| {
                     teardown_closure_called = true;
                 })
@@ -489,6 +580,45 @@ mod tests {
 
     }
 
+    // Group built with `nonstatic_group()` should run its teardown closure on drop, without an exit hook.
+    #[test] #[named]
+    fn test_nonstatic_group() {
+        let mut teardown_closure_called = false;
+        {
+            let group = MODULE_BASIC.nonstatic_group(function_name!())
+                .teardown(|_| {
+                    teardown_closure_called = true;
+                })
+                .build();
+
+            assert!(!group.is_static(),
+                "Group built with `nonstatic_group()` should not be static.");
+        }
+
+        assert!(teardown_closure_called,
+            "Group built with `nonstatic_group()` should run its teardown closure on drop, without an exit hook.");
+    }
+
+    // Group teardown panics should be caught and logged when the module is configured to catch them.
+    #[test] #[named]
+    fn test_teardown_catch_panics() {
+        let module = testing::unit(module_path!())
+            .teardown_catch_panics()
+            .nonstatic()
+            .build();
+
+        let namepath = {
+            let group = module.nonstatic_group(function_name!())
+                .teardown(|_| panic!("Intentional teardown failure"))
+                .build();
+
+            group.namepath_str().to_owned()
+        };
+
+        assert_eq!(vec![format!("Teardown panicked: {namepath}: Intentional teardown failure")], module.teardown_panics(),
+            "Group teardown panics should be caught and logged when the module is configured to catch them.");
+    }
+
     extern fn static_teardown_fn() {
         println!("STATIC_GROUP: {}::teardown_static() ran", STATIC_GROUP.namepath().path())
     }
@@ -509,10 +639,10 @@ mod tests {
         STATIC_GROUP.namepath();
     }
 
-    // Group constructed using `Module::local_group()` should not allow static teardown functions.
+    // Group constructed using `Module::nonstatic_group()` should not allow static teardown functions.
     #[test] #[named] #[should_panic]
     fn test_teardown_local_static_mismatch() {
-        MODULE_BASIC.local_group(function_name!())
+        MODULE_BASIC.nonstatic_group(function_name!())
             .teardown_static(static_teardown_fn)  // should panic
             .build();
     }
@@ -50,6 +50,7 @@
 //! ## File fixture directories
 //! 
 //! File fixtures for testing purposes may be stored (by default) relative to the crate's project directory in `./testing/fixtures`.
+//! The crate's project directory is determined by `CARGO_MANIFEST_DIR`, falling back to the current working directory.
 //! 
 //! The file structure within the base fixture directory reflects the test model's use-case and heirarchy:
 //! ```bash
@@ -62,17 +63,26 @@
 //! 
 //! The default fixture path f/or a model mirrors its heirarchy and namepath.
 //! 
+//! A module's `fixture_use_case(UseCase)` resolves its fixtures under another use-case's directory, so that one fixture
+//! tree may be shared between, e.g., unit and integration tests.
+//! 
 //! It is an error to build a test model with a fixture path that does not exist.
 //! 
 //! Fixture dirs must be explicitly configured during construction:
 //! - `using_fixture_dir()` uses a default calculated path.
 //! - `inherit_fixture_dir()` inherits the same path as its parent in the model heirarchy.
+//! - `inherit_fixture_dir_or_module()` inherits its Group's path if configured, otherwise its Module's (tests only).
 //! - `import_fixture_dir(Namepath)` imports a fixture directory from another testable model.
+//! - `import_fixture_path(key, Path)` imports an arbitrary directory by literal path, stored under a string key.
 //! 
 //! The aforementioned builder methods will verify that the configured path exists before construction.
 //! 
 //! After construction, a testable's [fixture_dir()](Testable::fixture_dir) can then be used retrieve the configured `Path`. Any imported fixture
-//! directories can be retrieved with [imported_fixture_dir()](Testable::imported_fixture_dir).
+//! directories can be retrieved with [imported_fixture_dir()](Testable::imported_fixture_dir), or
+//! [imported_fixture_path()](Testable::imported_fixture_path) for those imported by literal path.
+//! 
+//! Fixture files can be read directly with [fixture_bytes()](Testable::fixture_bytes). With the `json` or `toml` features
+//! enabled, `fixture_json()` and `fixture_toml()` will also deserialize them.
 //! 
 //! ## Temporary file directories
 //! 
@@ -82,13 +92,28 @@
 //! directory, within which each child component will have a subdirectory.
 //! 
 //! The base path for temporary directories can be re-configured away from the operating system's default, if neededed.
+//! Alternatively, `base_temp_dir_shared(prefix)` places a module's directories under a well-known shared path instead of
+//! a randomly generated one. Or, `use_existing_temp_dir(dir)` adopts a directory managed by the caller, which is never
+//! deleted on teardown.
 //! 
 //! Temporary directories must be explicitly configured during construction:
 //! - `using_temp_dir()` uses a default calculated path.
 //! - `inherit_temp_dir()` inherits the same path as its parent in the model heirarchy.
+//! - `inherit_temp_dir_scoped()` (tests only) uses a uniquely named subdirectory of its parent's path.
+//! 
+//! A module's `default_test_temp_dir()` and `default_test_fixture_dir()` apply `using_temp_dir()` and `using_fixture_dir()`
+//! to each of its tests that don't configure their own.
 //! 
 //! After construction, a model's `temp_dir()` can then be used retrieve the pre-created `Path`.
 //! 
+//! For debugging, temporary directories can be retained rather than deleted:
+//! - `keep_temp_dir_on_failure()` retains a test's temp dir if the test panics.
+//! - `keep_temp_dirs()` retains a module's base temp dir, along with the temp dirs of its failed tests.
+//! 
+//! A test's `run()` executes its body, tears down, and logs the outcome before re-raising any panic.
+//! 
+//! Tests of a benchmark module can time a closure with `bench()`. The module's `bench_report()` summarizes them.
+//! 
 //! # Example Usage
 //! ```rust
 //! fn main() {}
@@ -143,15 +168,17 @@ pub mod namepath;
 pub mod test;
 pub mod group;
 pub mod module;
+pub mod bench;
 
-use std::path::{PathBuf, Path};
+use std::{collections::HashMap, path::{PathBuf, Path}};
 use anyhow::Context;
 use once_cell::sync::Lazy;
 
-pub use module::{Module, ModuleBuilder};
+pub use module::{Module, ModuleBuilder, registered_modules};
 pub use group::{Group, GroupBuilder};
 pub use test::{Test, TestBuilder};
 pub use namepath::{Namepath, NamepathTrait};
+pub use bench::{BenchReport, BenchResult};
 
 pub mod prelude {
     pub use function_name::named;
@@ -167,6 +194,16 @@ pub const fn module(func: fn() -> Module) -> StaticModule {
     StaticModule::new(func)
 }
 
+/// Creates a [StaticModule] for unit tests of the calling module, without temp or fixture dirs.
+/// 
+/// `static TESTING: testing::StaticModule = testing::quick_unit!();`
+#[macro_export]
+macro_rules! quick_unit {
+    () => {
+        $crate::module(|| $crate::unit(module_path!()).build())
+    };
+}
+
 pub const fn group<'module,'func>(func: fn() -> Group<'module,'func>) -> StaticGroup<'module,'func> {
     StaticGroup::new(func)
 }
@@ -189,6 +226,34 @@ pub trait Testable {
     fn fixture_dir(&self) -> &Path;
     /// Returns the fixture directory for another testable, if previous imported during configuration. Canonical.
     fn imported_fixture_dir(&self, namepath: &Namepath) -> &Path;
+    /// Returns an arbitrary directory previously imported by key during configuration. Canonical.
+    fn imported_fixture_path(&self, key: &str) -> &Path;
+
+    /// Reads a file, relative to the fixture directory, as bytes.
+    fn fixture_bytes(&self, relative: impl AsRef<Path>) -> anyhow::Result<Vec<u8>> {
+        let filepath = self.fixture_dir().join(relative);
+        std::fs::read(&filepath)
+            .context(format!("Unable to read fixture file: {}", filepath.to_str().unwrap()))
+    }
+
+    /// Reads and deserializes a JSON file, relative to the fixture directory.
+    #[cfg(feature = "json")]
+    fn fixture_json<T: serde::de::DeserializeOwned>(&self, relative: impl AsRef<Path>) -> anyhow::Result<T> {
+        let filepath = self.fixture_dir().join(relative);
+        let bytes = self.fixture_bytes(&filepath)?;
+        serde_json::from_slice(&bytes)
+            .context(format!("Unable to parse JSON fixture file: {}", filepath.to_str().unwrap()))
+    }
+
+    /// Reads and deserializes a TOML file, relative to the fixture directory.
+    #[cfg(feature = "toml")]
+    fn fixture_toml<T: serde::de::DeserializeOwned>(&self, relative: impl AsRef<Path>) -> anyhow::Result<T> {
+        let filepath = self.fixture_dir().join(relative);
+        let text = String::from_utf8(self.fixture_bytes(&filepath)?)
+            .context(format!("Fixture file is not valid UTF-8: {}", filepath.to_str().unwrap()))?;
+        toml::from_str(&text)
+            .context(format!("Unable to parse TOML fixture file: {}", filepath.to_str().unwrap()))
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -225,9 +290,60 @@ impl Model {
     }
 }
 
+/// Asserts that two directory trees have the same relative file paths with byte-equal contents.
+/// Panics naming the first file that differs. Typically used to compare a temp dir against an expected fixture dir.
+pub fn assert_dirs_eq(actual: &Path, expected: &Path) {
+    let actual_files = relative_file_paths(actual);
+    let expected_files = relative_file_paths(expected);
+
+    // both lists are sorted, so the first mismatch is the first differing path
+    for i in 0..actual_files.len().max(expected_files.len()) {
+        match (actual_files.get(i), expected_files.get(i)) {
+            (Some(actual_file), Some(expected_file)) if actual_file == expected_file => {},
+            (Some(actual_file), Some(expected_file)) => panic!("Directory trees differ at: {} (Unexpected file in {})",
+                actual_file.min(expected_file).to_str().unwrap(),
+                if actual_file < expected_file { "actual" } else { "expected" }),
+            (Some(actual_file), None) => panic!("Directory trees differ at: {} (Unexpected file in actual)",
+                actual_file.to_str().unwrap()),
+            (None, Some(expected_file)) => panic!("Directory trees differ at: {} (Unexpected file in expected)",
+                expected_file.to_str().unwrap()),
+            (None, None) => unreachable!()
+        }
+    }
+
+    for file in &expected_files {
+        let actual_bytes = std::fs::read(actual.join(file)).unwrap();
+        let expected_bytes = std::fs::read(expected.join(file)).unwrap();
+        assert!(actual_bytes == expected_bytes, "Directory trees differ at: {} (Contents)", file.to_str().unwrap());
+    }
+}
+
+// Lists every file within a directory tree, relative to its root, sorted.
+fn relative_file_paths(root: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let entries = std::fs::read_dir(&dir)
+            .context(format!("Unable to read directory: {}", dir.to_str().unwrap()))
+            .unwrap();
+
+        for entry in entries {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else {
+                files.push(path.strip_prefix(root).unwrap().to_path_buf());
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
+
 // Helper function for test models configuring their temp_dir during `build()`.
 pub(crate) fn build_temp_dir(namepath: &Namepath, base_temp_dir: &Path) -> PathBuf {
-    let temp_dir = base_temp_dir.join(PathBuf::from_iter(namepath.components().iter()));
+    let temp_dir = base_temp_dir.join(namepath.safe_dir());
 
     if !temp_dir.exists() {
         std::fs::create_dir_all(&temp_dir)
@@ -238,12 +354,39 @@ pub(crate) fn build_temp_dir(namepath: &Namepath, base_temp_dir: &Path) -> PathB
     temp_dir.canonicalize().unwrap()
 }
 
+// Anchors the fixture dir at the crate being tested (CARGO_MANIFEST_DIR), as the current working directory differs
+// between running `cargo test` from the workspace root and from within a member crate. Falls back to a CWD-relative
+// path when not run by cargo.
 pub(crate) fn build_fixture_dir(namepath: &Namepath, use_case: UseCase) -> PathBuf {
-    // path: ./ testing / fixtures / [ unit | integration | benchmark ] / { module } / { group ... } / { test } 
-    let fixture_dir = PathBuf::from(strings::TESTING)
-        .join(strings::FIXTURES)
-        .join(use_case.to_str())
-        .join(namepath.testing_dir());
+    let fixture_dir = resolve_fixture_dir(namepath, use_case);
+    if !fixture_dir.exists() {
+        // report the missing dir where it's expected, rather than relative to the current working directory
+        let fixture_dir = anchor_to_manifest_dir(namepath.fixture_path(use_case));
+
+        // fixtures placed under the wrong use-case are an easy mistake to make, so point it out
+        let misplaced_dir = [UseCase::Unit, UseCase::Integration, UseCase::Benchmark].into_iter()
+            .filter(|other_use_case| *other_use_case != use_case)
+            .map(|other_use_case| anchor_to_manifest_dir(namepath.fixture_path(other_use_case)))
+            .find(|dir| dir.exists());
+
+        if let Some(misplaced_dir) = misplaced_dir {
+            panic!("Module `fixture directory` does not exist: {} (Found a fixture directory for a different use-case, \
+                which may be misplaced: {})", fixture_dir.to_str().unwrap(), misplaced_dir.to_str().unwrap());
+        }
+
+        // otherwise, assume first-time setup and suggest how to scaffold it
+        let use_case_dir = anchor_to_manifest_dir(
+            PathBuf::from(strings::TESTING).join(strings::FIXTURES).join(use_case.to_str()));
+        let hint = if use_case_dir.exists() {
+            String::new()
+        } else {
+            format!("No fixture directories exist for the `{}` use-case yet. ", use_case.to_str())
+        };
+
+        panic!("Module `fixture directory` does not exist: {} ({}To create it, run: mkdir -p {})",
+            fixture_dir.to_str().unwrap(), hint, fixture_dir.to_str().unwrap());
+    }
+
     let fixture_dir = fixture_dir.canonicalize()
         .context(format!("Module `fixture directory` does not exist: {}", fixture_dir.to_str().unwrap()))
         .unwrap();
@@ -251,12 +394,69 @@ pub(crate) fn build_fixture_dir(namepath: &Namepath, use_case: UseCase) -> PathB
     fixture_dir
 }
 
+fn resolve_fixture_dir(namepath: &Namepath, use_case: UseCase) -> PathBuf {
+    // path: ./ testing / fixtures / [ unit | integration | benchmark ] / { module } / { group ... } / { test } 
+    let fixture_dir = namepath.fixture_path(use_case);
+    let anchored_fixture_dir = anchor_to_manifest_dir(fixture_dir.to_owned());
+
+    if anchored_fixture_dir.exists() {
+        anchored_fixture_dir
+    } else {
+        fixture_dir
+    }
+}
+
+// Joins a relative path onto CARGO_MANIFEST_DIR, when run by cargo.
+fn anchor_to_manifest_dir(path: PathBuf) -> PathBuf {
+    std::env::var_os(strings::CARGO_MANIFEST_DIR)
+        .map(|manifest_dir| PathBuf::from(manifest_dir).join(&path))
+        .unwrap_or(path)
+}
+
+// Lists the namepaths that were imported, so that a near-miss lookup is easy to spot.
+pub(crate) fn imported_fixture_dir_not_found(
+    namepath: &Namepath,
+    imported_fixture_dirs: Option<&HashMap<Namepath, PathBuf>>
+) -> String {
+    let mut available: Vec<&str> = imported_fixture_dirs
+        .map(|dirs| dirs.keys().map(|namepath| namepath.path()).collect())
+        .unwrap_or_default();
+    available.sort();
+
+    let available = if available.is_empty() { "none".to_string() } else { available.join(", ") };
+    format!("Imported fixture dir not found for namepath: {} (Available: {})", namepath.path(), available)
+}
+
+pub(crate) fn build_imported_fixture_path(path: &Path) -> PathBuf {
+    path.canonicalize()
+        .context(format!("Imported fixture path does not exist: {}", path.to_str().unwrap()))
+        .unwrap()
+}
+
 pub(crate) mod strings {
     pub(crate) const TESTING: &'static str = "testing";
     pub(crate) const FIXTURES: &'static str = "fixtures";
+    pub(crate) const CARGO_MANIFEST_DIR: &'static str = "CARGO_MANIFEST_DIR";
+    pub(crate) const SHARED_TEMP_DIR: &'static str = "asmov_testing";
 }
 
 
 #[cfg(test)]
 pub(crate) mod tests {
+    use crate::{self as testing, prelude::*, UseCase};
+
+    static QUICK_UNIT: testing::StaticModule = testing::quick_unit!();
+
+    // Should create a static unit Module for the calling module, without temp or fixture dirs.
+    #[test] #[named]
+    fn test_quick_unit() {
+        let test = QUICK_UNIT.test(function_name!()).build();
+
+        assert_eq!(UseCase::Unit, *QUICK_UNIT.use_case());
+        assert_eq!(module_path!(), QUICK_UNIT.namepath_str());
+        assert!(QUICK_UNIT.is_static());
+        assert!(QUICK_UNIT.temp_dir.is_none() && QUICK_UNIT.fixture_dir.is_none(),
+            "Should create a static unit Module for the calling module, without temp or fixture dirs.");
+        assert_eq!(function_name!(), test.name());
+    }
 }
\ No newline at end of file
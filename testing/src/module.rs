@@ -2,11 +2,11 @@
 //! Testing for a module
 //! This is testing
 
-use std::{collections::HashMap, ffi::OsStr, path::{PathBuf, Path}, sync::Mutex};
+use std::{collections::HashMap, ffi::OsStr, path::{PathBuf, Path}, sync::Mutex, time::{Duration, SystemTime}};
 use once_cell::sync::Lazy;
 use anyhow::{self, bail, Context};
 use rand::{self, Rng};
-use crate::{UseCase, Testable, NamepathTrait, GroupBuilder, TestBuilder, namepath::Namepath};
+use crate::{UseCase, Testable, NamepathTrait, GroupBuilder, TestBuilder, namepath::Namepath, bench::{BenchReport, BenchResults}};
 
 const MAX_RAND_DIR_RETRIES: i32 = 64;
 const MAX_RAND_DIR_CHARS: i32 = 8;
@@ -15,10 +15,21 @@ const MAX_RAND_DIR_CHARS: i32 = 8;
 pub struct Module {
     pub(crate) namepath: Namepath,
     pub(crate) use_case: UseCase,
+    pub(crate) fixture_use_case: UseCase,
     pub(crate) base_temp_dir: Option<PathBuf>,
     pub(crate) temp_dir: Option<PathBuf>,
     pub(crate) fixture_dir: Option<PathBuf>,
-    pub(crate) imported_fixture_dirs: Option<HashMap<Namepath, PathBuf>>
+    pub(crate) imported_fixture_dirs: Option<HashMap<Namepath, PathBuf>>,
+    pub(crate) imported_fixture_paths: Option<HashMap<String, PathBuf>>,
+    pub(crate) keep_temp_dirs: bool,
+    pub(crate) shared_base_temp_dir: bool,
+    pub(crate) is_static: bool,
+    pub(crate) default_test_temp_dir: bool,
+    pub(crate) default_test_fixture_dir: bool,
+    pub(crate) teardown_catch_panics: bool,
+    pub(crate) teardown_panics: TeardownPanics,
+    pub(crate) test_namepaths: TestNamepaths,
+    pub(crate) bench_results: BenchResults
 }
 
 impl Module {
@@ -26,10 +37,48 @@ impl Module {
         &self.namepath
     }
 
+    /// Shorthand for `namepath().path()`.
+    pub fn namepath_str(&self) -> &str {
+        self.namepath.path()
+    }
+
     pub fn use_case(&self) -> &UseCase {
         &self.use_case
     }
 
+    /// The use-case whose directory fixtures are resolved under. This is the module's own use-case unless overridden.
+    pub fn fixture_use_case(&self) -> &UseCase {
+        &self.fixture_use_case
+    }
+
+    /// Whether both modules represent the same module under test, by namepath and use-case.
+    /// Unlike `==`, this disregards configuration such as the randomly named temp dirs of each instance.
+    pub fn same_as(&self, other: &Module) -> bool {
+        self.namepath == other.namepath && self.use_case == other.use_case
+    }
+
+    /// Whether this module was built as static, with its teardown handled by a process exit hook.
+    pub fn is_static(&self) -> bool {
+        self.is_static
+    }
+
+    /// The panics caught from teardown closures, as logged, when built with `teardown_catch_panics()`.
+    pub fn teardown_panics(&self) -> Vec<String> {
+        self.teardown_panics.0.lock().unwrap().clone()
+    }
+
+    /// The namepaths of each test built under this module or its groups, in order of construction.
+    /// A harness may diff these against an expected manifest to detect tests that never ran.
+    pub fn test_namepaths(&self) -> Vec<Namepath> {
+        self.test_namepaths.0.lock().unwrap().clone()
+    }
+
+    /// Summarizes the benchmarks recorded by this module's tests with `Test::bench()`. Benchmark modules only.
+    pub fn bench_report(&self) -> BenchReport {
+        assert_eq!(UseCase::Benchmark, self.use_case, "Module must be a benchmark to report benchmarks");
+        self.bench_results.report()
+    }
+
     pub fn base_temp_dir(&self) -> &Path {
         &self.base_temp_dir.as_ref().context("Module `base temp dir` is not configured").unwrap()
     }
@@ -38,32 +87,83 @@ impl Module {
         &self.temp_dir.as_ref().context("Module `temp dir` is not configured").unwrap()
     }
 
-    // Creates a GroupBuilder configured as static. This is the expected usage.
+    /// Creates a GroupBuilder configured as static. This is the expected usage within `#[test]` modules.
+    /// Static groups may only use `teardown_static()`, which registers a process exit hook.
+    /// Panics if this module is non-static.
     pub fn group(&self, name: &str) -> GroupBuilder {
+        assert!(self.is_static, "Non-static Module must use `nonstatic_group()` to create a Group");
         GroupBuilder::new(self, name, true) 
     }
 
-    // Creates a GroupBuilder configured as non-static. This is expected to be used in testing of this crate.
-    pub fn local_group(&self, name: &str) -> GroupBuilder {
+    /// Creates a GroupBuilder configured as non-static, for harnesses that own the group's lifetime.
+    /// Non-static groups may only use `teardown()`, which runs on drop. No process exit hook is registered.
+    pub fn nonstatic_group(&self, name: &str) -> GroupBuilder {
         GroupBuilder::new(self, name, false) 
     }
 
+    #[deprecated(note = "Use `nonstatic_group()` instead")]
+    pub fn local_group(&self, name: &str) -> GroupBuilder {
+        self.nonstatic_group(name)
+    }
+
     /// Creates a [TestBuilder].
     pub fn test(&self, name: &str) -> TestBuilder {
         TestBuilder::new(&self, None, name)
     }
 
+    /// Removes random base temp dirs that were left behind for a module by a previous process that exited without
+    /// running its teardown hook (e.g., force-killed). Only subdirectories of `base` that match the module's squashed
+    /// namepath prefix and are older than `max_age` are removed.
+    /// 
+    /// Returns the number of directories removed. Intended to be called by a test harness at startup.
+    pub fn prune_orphaned_temp_dirs(base: &Path, module_prefix: &str, max_age: Duration) -> usize {
+        let entries = match std::fs::read_dir(base) {
+            Ok(entries) => entries,
+            Err(_) => return 0
+        };
+
+        let now = SystemTime::now();
+        entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+            .filter(|entry| is_random_subdir_name(&entry.file_name(), module_prefix))
+            .filter(|entry| {
+                entry.metadata()
+                    .and_then(|metadata| metadata.modified())
+                    .is_ok_and(|modified| now.duration_since(modified).is_ok_and(|age| age > max_age))
+            })
+            .filter(|entry| std::fs::remove_dir_all(entry.path()).is_ok())
+            .count()
+    }
+
     pub(crate) fn try_imported_fixture_dir(&self, namepath: &Namepath) -> anyhow::Result<&Path> {
-        Ok(self.imported_fixture_dirs.as_ref()
-            .context("Module `imported fixture dirs` is not configured")?
-            .get(namepath)
-            .context(format!("Imported fixture dir not found for namepath: {}", namepath.path()))?
+        self.imported_fixture_dirs.as_ref()
+            .and_then(|imported_fixture_dirs| imported_fixture_dirs.get(namepath))
+            .map(PathBuf::as_path)
+            .with_context(|| crate::imported_fixture_dir_not_found(namepath, self.imported_fixture_dirs.as_ref()))
+    }
+
+    pub(crate) fn try_imported_fixture_path(&self, key: &str) -> anyhow::Result<&Path> {
+        Ok(self.imported_fixture_paths.as_ref()
+            .context("Module `imported fixture paths` is not configured")?
+            .get(key)
+            .context(format!("Imported fixture path not found for key: {}", key))?
             .as_path())
     }
 
+    // A shared or existing base temp dir may be in use by others, so only this module's own temp dir is deleted.
+    fn teardown_temp_dir(&self) -> Option<PathBuf> {
+        if self.shared_base_temp_dir {
+            self.temp_dir.clone()
+        } else {
+            self.base_temp_dir.clone()
+        }
+    }
+
     fn teardown(&mut self) {
         let mut teardown = Teardown {
-            base_temp_dir: self.base_temp_dir.take()
+            base_temp_dir: self.teardown_temp_dir(),
+            keep_base_temp_dir: self.keep_temp_dirs
         };
 
         teardown.destroy();
@@ -78,16 +178,68 @@ impl Testable for Module {
     fn imported_fixture_dir(&self, namepath: &Namepath) -> &Path {
         self.try_imported_fixture_dir(namepath).unwrap()
     }
+
+    fn imported_fixture_path(&self, key: &str) -> &Path {
+        self.try_imported_fixture_path(key).unwrap()
+    }
+}
+
+/// The panics caught from teardown closures. Shared by the module's groups, which only hold a reference to it.
+#[derive(Debug, Default)]
+pub(crate) struct TeardownPanics(Mutex<Vec<String>>);
+
+impl TeardownPanics {
+    // Catches a panic from a teardown closure, logging it with the namepath rather than letting it escape `Drop`
+    pub(crate) fn catch(&self, namepath: &Namepath, func: impl FnOnce()) {
+        if let Err(payload) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(func)) {
+            let message = payload.downcast_ref::<&str>().map(|msg| msg.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+
+            let log = format!("Teardown panicked: {}: {}", namepath.path(), message);
+            eprintln!("{log}");
+            self.0.lock().unwrap().push(log);
+        }
+    }
+}
+
+impl PartialEq for TeardownPanics {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other) || *self.0.lock().unwrap() == *other.0.lock().unwrap()
+    }
+}
+
+impl Eq for TeardownPanics {}
+
+/// The namepaths of tests built under a module, recorded by `TestBuilder::build()`.
+#[derive(Debug, Default)]
+pub(crate) struct TestNamepaths(Mutex<Vec<Namepath>>);
+
+impl TestNamepaths {
+    pub(crate) fn record(&self, namepath: &Namepath) {
+        self.0.lock().unwrap().push(namepath.to_owned());
+    }
+}
+
+impl PartialEq for TestNamepaths {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other) || *self.0.lock().unwrap() == *other.0.lock().unwrap()
+    }
 }
 
+impl Eq for TestNamepaths {}
+
 struct Teardown {
-    base_temp_dir: Option<PathBuf>
+    base_temp_dir: Option<PathBuf>,
+    keep_base_temp_dir: bool
 }
 
 impl Teardown {
     pub(crate) fn destroy(&mut self) {
         if let Some(dir) = &self.base_temp_dir {
-            if dir.exists() && std::fs::remove_dir_all(&dir).is_err() {
+            if self.keep_base_temp_dir {
+                eprintln!("Retaining base temp dir: {}", dir.to_str().unwrap());
+            } else if dir.exists() && std::fs::remove_dir_all(&dir).is_err() {
                 eprintln!("Unable to delete base temp dir: {}", dir.to_str().unwrap());
             }
         }
@@ -102,32 +254,51 @@ impl Drop for Module {
 
 pub struct ModuleBuilder<'func> {
     pub(crate) use_case: UseCase,
+    pub(crate) fixture_use_case: Option<UseCase>,
     pub(crate) module_path: String,
     pub(crate) base_temp_dir: PathBuf,
     pub(crate) using_temp_dir: bool,
     pub(crate) using_fixture_dir: bool,
-    pub(crate) imported_fixture_dirs: Option<HashMap<Namepath, PathBuf>>,
+    // resolved by build(), once the fixture use-case is final
+    pub(crate) imported_fixture_namepaths: Vec<Namepath>,
+    pub(crate) imported_fixture_paths: Option<HashMap<String, PathBuf>>,
     pub(crate) setup_func: Option<Box<dyn FnOnce(&mut Module) + 'func>>,
     pub(crate) static_teardown_func: Option<Box<extern fn()>>,
-    pub(crate) is_static: bool 
+    pub(crate) is_static: bool,
+    pub(crate) keep_temp_dirs: bool,
+    pub(crate) shared_temp_dir_prefix: Option<String>,
+    pub(crate) existing_temp_dir: Option<PathBuf>,
+    pub(crate) required_fixtures: Vec<PathBuf>,
+    pub(crate) teardown_catch_panics: bool,
+    pub(crate) default_test_temp_dir: bool,
+    pub(crate) default_test_fixture_dir: bool
 }
 
 impl<'func> ModuleBuilder<'func> {
     pub(crate) fn new(module_path: &str, use_case: crate::UseCase) -> Self {
         ModuleBuilder {
             use_case,
+            fixture_use_case: None,
             module_path: String::from(module_path),
             base_temp_dir: std::env::temp_dir(),
             using_temp_dir: false,
             using_fixture_dir: false,
-            imported_fixture_dirs: None,
+            imported_fixture_namepaths: Vec::new(),
+            imported_fixture_paths: None,
             setup_func: None,
             static_teardown_func: None,
             is_static: true,
+            keep_temp_dirs: false,
+            shared_temp_dir_prefix: None,
+            existing_temp_dir: None,
+            required_fixtures: Vec::new(),
+            teardown_catch_panics: false,
+            default_test_temp_dir: false,
+            default_test_fixture_dir: false,
         }
     }
 
-    fn create_random_subdir(base_dir: &Path, prefix: &str) -> anyhow::Result<PathBuf> {
+    pub(crate) fn create_random_subdir(base_dir: &Path, prefix: &str) -> anyhow::Result<PathBuf> {
         let mut randgen = rand::thread_rng();
         let mut random_dir;
 
@@ -150,12 +321,25 @@ impl<'func> ModuleBuilder<'func> {
         bail!("Unable to create temporary directory in: {}", base_dir.to_str().unwrap())
     }
 
+    // path: { base_dir } / asmov_testing / { prefix }
+    fn create_shared_subdir(base_dir: &Path, prefix: &str) -> anyhow::Result<PathBuf> {
+        let shared_dir = base_dir.join(crate::strings::SHARED_TEMP_DIR).join(prefix);
+        std::fs::create_dir_all(&shared_dir)?;
+        Ok(shared_dir.canonicalize()?)
+    }
+
     pub fn build(self) -> Module {
         let namepath = Namepath::module(self.use_case, self.module_path);
 
         let base_temp_dir;
         let temp_dir = if self.using_temp_dir {
-            base_temp_dir = Some( Self::create_random_subdir(&self.base_temp_dir, &namepath.squash()) // todo: use squashed prefix
+            let subdir = match (&self.existing_temp_dir, &self.shared_temp_dir_prefix) {
+                (Some(existing_dir), _) => Ok(existing_dir.to_owned()),
+                (None, Some(prefix)) => Self::create_shared_subdir(&self.base_temp_dir, prefix),
+                (None, None) => Self::create_random_subdir(&self.base_temp_dir, &namepath.squash()) // todo: use squashed prefix
+            };
+
+            base_temp_dir = Some( subdir
                 .context(format!("Unable to create temporary directory in base: {}", &self.base_temp_dir.to_str().unwrap()))
                 .unwrap() );
 
@@ -166,20 +350,52 @@ impl<'func> ModuleBuilder<'func> {
         };
 
         let fixture_dir = if self.using_fixture_dir {
-            Some( crate::build_fixture_dir(&namepath, self.use_case) )
+            Some( crate::build_fixture_dir(&namepath, self.fixture_use_case.unwrap_or(self.use_case)) )
         } else {
             None
         };
 
-        let imported_fixture_dirs = self.imported_fixture_dirs;
+        for required_fixture in &self.required_fixtures {
+            let filepath = fixture_dir.as_ref().expect("Fixture dir should be in use").join(required_fixture);
+            assert!(filepath.is_file(), "Required fixture file does not exist: {}", filepath.to_str().unwrap());
+        }
+
+        let fixture_use_case = self.fixture_use_case.unwrap_or(self.use_case);
+        let imported_fixture_dirs = if self.imported_fixture_namepaths.is_empty() {
+            None
+        } else {
+            Some(self.imported_fixture_namepaths.into_iter()
+                .map(|namepath| {
+                    let dir = crate::build_fixture_dir(&namepath, fixture_use_case);
+                    let dir = dir.canonicalize()
+                        .context(format!("Imported fixture dir does not exist: {}", &dir.to_str().unwrap()))
+                        .unwrap();
+
+                    (namepath, dir)
+                })
+                .collect())
+        };
+
+        let imported_fixture_paths = self.imported_fixture_paths;
 
         let mut module = Module {
             namepath,
             use_case: self.use_case,
+            fixture_use_case: self.fixture_use_case.unwrap_or(self.use_case),
             base_temp_dir,
             temp_dir,
             fixture_dir,
-            imported_fixture_dirs
+            imported_fixture_dirs,
+            imported_fixture_paths,
+            keep_temp_dirs: self.keep_temp_dirs,
+            shared_base_temp_dir: self.shared_temp_dir_prefix.is_some() || self.existing_temp_dir.is_some(),
+            is_static: self.is_static,
+            default_test_temp_dir: self.default_test_temp_dir,
+            default_test_fixture_dir: self.default_test_fixture_dir,
+            teardown_catch_panics: self.teardown_catch_panics,
+            teardown_panics: TeardownPanics::default(),
+            test_namepaths: TestNamepaths::default(),
+            bench_results: BenchResults::default()
         };
 
         if let Some(setup_fn) = self.setup_func {
@@ -191,9 +407,12 @@ impl<'func> ModuleBuilder<'func> {
         }
 
         if self.is_static {
+            STATIC_MODULE_REGISTRY.lock().unwrap().push(module.namepath.clone());
+
             let mut teardown_list = STATIC_TEARDOWN_QUEUE.lock().unwrap();
             teardown_list.push(Teardown {
-                base_temp_dir: module.base_temp_dir.clone()
+                base_temp_dir: module.teardown_temp_dir(),
+                keep_base_temp_dir: module.keep_temp_dirs
             });
 
             if teardown_list.len() == 1 {
@@ -222,19 +441,42 @@ impl<'func> ModuleBuilder<'func> {
         self
     }
 
+    /// Verifies that each of the named files exists within this module's fixture dir when built, panicking with the
+    /// full path of the first that is missing. Implies `using_fixture_dir()`.
+    pub fn require_fixtures<P>(mut self, filenames: &[P]) -> Self
+    where
+        P: AsRef<Path>
+    {
+        self.using_fixture_dir = true;
+        self.required_fixtures.extend(filenames.iter().map(|filename| filename.as_ref().to_owned()));
+        self
+    }
+
+    /// Resolves fixtures under another use-case's directory, for fixture trees that are shared across use-cases.
+    /// Only fixture paths are affected. Namepaths, and thus the fixture subdirectories within the use-case directory,
+    /// are still derived using the module's own use-case. Imported fixture dirs are resolved under it as well.
+    pub fn fixture_use_case(mut self, use_case: UseCase) -> Self {
+        self.fixture_use_case = Some(use_case);
+        self
+    }
+
     pub fn import_fixture_dir(mut self, namepath: &Namepath) -> Self {
-        let dir = crate::build_fixture_dir(&namepath, self.use_case);
-        let dir = dir.canonicalize()
-            .context(format!("Imported fixture dir does not exist: {}", &dir.to_str().unwrap()))
-            .unwrap();
+        self.imported_fixture_namepaths.push(namepath.to_owned());
+        self
+    }
 
-        if self.imported_fixture_dirs.is_none() {
-            self.imported_fixture_dirs = Some(HashMap::new());
+    /// Imports an arbitrary existing directory under `key`, bypassing namepath resolution.
+    /// Retrievable with [imported_fixture_path()](Testable::imported_fixture_path).
+    pub fn import_fixture_path(mut self, key: &str, path: impl AsRef<Path>) -> Self {
+        let dir = crate::build_imported_fixture_path(path.as_ref());
+
+        if self.imported_fixture_paths.is_none() {
+            self.imported_fixture_paths = Some(HashMap::new());
         }
 
-        self.imported_fixture_dirs.as_mut().expect("Option should exist")
-            .insert(namepath.to_owned(), dir);
-        
+        self.imported_fixture_paths.as_mut().expect("Option should exist")
+            .insert(key.to_owned(), dir);
+
         self
     }
 
@@ -243,6 +485,65 @@ impl<'func> ModuleBuilder<'func> {
         self
     }
 
+    /// Places this module's temp dir tree under a well-known shared base, `{ base temp dir } / asmov_testing / { prefix }`,
+    /// rather than a randomly named subdir. This eases inspection and targeted cleanup across test binaries.
+    /// 
+    /// Modules (and concurrent runs of the same module) that share a prefix may collide, as their temp dirs are no
+    /// longer isolated by a random subdir. Teardown only deletes this module's own temp dir, not the shared base.
+    pub fn base_temp_dir_shared(mut self, prefix: &str) -> Self {
+        self.shared_temp_dir_prefix = Some(prefix.to_owned());
+        self
+    }
+
+    /// Adopts an existing directory, managed by the caller, as this module's base temp dir rather than creating a
+    /// randomly named subdir. The module's temp dir tree is built within it as usual.
+    /// 
+    /// The caller owns the directory, so teardown only deletes this module's own temp dir within it, never the
+    /// directory itself.
+    pub fn use_existing_temp_dir<P>(mut self, dir: &P) -> Self
+    where
+        P: ?Sized + AsRef<OsStr>
+    {
+        let dir = PathBuf::from(dir);
+        let dir = dir.canonicalize()
+            .context(format!("Existing temporary directory does not exist: {}", &dir.to_str().unwrap()))
+            .unwrap();
+
+        self.existing_temp_dir = Some(dir);
+        self.using_temp_dir = true;
+        self
+    }
+
+    /// For debugging. Retains the module's base temp dir on teardown, printing its path, rather than deleting it.
+    /// Tests within this module will likewise retain their temp dirs if they fail.
+    pub fn keep_temp_dirs(mut self) -> Self {
+        self.keep_temp_dirs = true;
+        self
+    }
+
+    /// Configures this module to use a temp dir, and each of its tests to use their own unless they configure otherwise.
+    /// Equivalent to calling `using_temp_dir()` on every test that makes no temp dir choice of its own.
+    pub fn default_test_temp_dir(mut self) -> Self {
+        self.using_temp_dir = true;
+        self.default_test_temp_dir = true;
+        self
+    }
+
+    /// Configures each of this module's tests to use a fixture dir unless they configure otherwise.
+    /// Equivalent to calling `using_fixture_dir()` on every test that makes no fixture dir choice of its own.
+    pub fn default_test_fixture_dir(mut self) -> Self {
+        self.default_test_fixture_dir = true;
+        self
+    }
+
+    /// Catches panics from the teardown closures of this module's groups and tests, logging each with its namepath
+    /// rather than letting it unwind from `Drop`, which may abort the process. Caught panics are listed by
+    /// `teardown_panics()`.
+    pub fn teardown_catch_panics(mut self) -> Self {
+        self.teardown_catch_panics = true;
+        self
+    }
+
     pub fn setup(mut self, func: impl FnOnce(&mut Module) + 'func) -> Self {
         self.setup_func = Some(Box::new(func));
         self
@@ -261,6 +562,17 @@ impl<'func> ModuleBuilder<'func> {
     }
 }
 
+// Matches the names created by `ModuleBuilder::create_random_subdir()`: { prefix }.{ random alphanumeric chars }
+fn is_random_subdir_name(name: &OsStr, prefix: &str) -> bool {
+    name.to_str()
+        .and_then(|name| name.strip_prefix(prefix))
+        .and_then(|name| name.strip_prefix('.'))
+        .is_some_and(|rand_chars| {
+            rand_chars.len() == MAX_RAND_DIR_CHARS as usize
+                && rand_chars.chars().all(|c| c.is_ascii_alphanumeric())
+        })
+}
+
 extern fn default_static_func() {
     let mut teardown_list = STATIC_TEARDOWN_QUEUE.lock().unwrap();
     while let Some(mut teardown) = teardown_list.pop() {
@@ -269,10 +581,16 @@ extern fn default_static_func() {
 }
 
 static STATIC_TEARDOWN_QUEUE: Lazy<Mutex<Vec<Teardown>>> = Lazy::new(|| { Mutex::new(Vec::new()) });
+static STATIC_MODULE_REGISTRY: Lazy<Mutex<Vec<Namepath>>> = Lazy::new(|| { Mutex::new(Vec::new()) });
+
+/// Returns the namepaths of every static [Module] built so far, in the order that they were built.
+pub fn registered_modules() -> Vec<Namepath> {
+    STATIC_MODULE_REGISTRY.lock().unwrap().clone()
+}
 
 #[cfg(test)]
 mod tests {
-    use std::path::PathBuf;
+    use std::{path::PathBuf, time::Duration};
     use crate::prelude::*;
     use crate::{self as testing, NamepathTrait, UseCase, Namepath, strings, namepath};
 
@@ -352,6 +670,54 @@ mod tests {
             .build();
     }
 
+    // Module should adopt an existing temp dir as its base, without deleting it on teardown.
+    #[test] #[named]
+    fn test_use_existing_temp_dir() {
+        let existing_temp_dir = {
+            let dir = std::env::temp_dir()
+                .join(namepath::squash(&concat!(module_path!(), function_name!())));
+
+            if !dir.exists() {
+                std::fs::create_dir(&dir).unwrap(); // needs manual teardown
+            }
+
+            dir.canonicalize().unwrap()
+        };
+
+        let module = testing::unit(module_path!())
+            .nonstatic()
+            .use_existing_temp_dir(&existing_temp_dir)
+            .build();
+
+        // Should use the existing dir as the module's base temp dir
+        assert_eq!(existing_temp_dir, module.base_temp_dir(),
+            "Should use the existing dir as the module's base temp dir");
+        let temp_dir = module.temp_dir().to_owned();
+        assert!(temp_dir.starts_with(&existing_temp_dir));
+
+        drop(module);
+
+        // Should retain the existing dir on teardown, deleting only the module's own temp dir
+        assert!(existing_temp_dir.exists() && !temp_dir.exists(),
+            "Should retain the existing dir on teardown, deleting only the module's own temp dir");
+
+        std::fs::remove_dir_all(&existing_temp_dir).unwrap();
+    }
+
+    // Module should record the namepath of each test built under it, in order.
+    #[test] #[named]
+    fn test_test_namepaths() {
+        let module = testing::unit(module_path!())
+            .nonstatic()
+            .build();
+
+        let test_a = module.test(concat!(function_name!(), "_a")).build();
+        let test_b = module.test(concat!(function_name!(), "_b")).build();
+
+        assert_eq!(vec![test_a.namepath().to_owned(), test_b.namepath().to_owned()], module.test_namepaths(),
+            "Module should record the namepath of each test built under it, in order.");
+    }
+
     // Module should not allow configuration of a base temp dir with a non-existing path.
     #[test] #[should_panic]
     fn test_base_temp_dir_nonexistant() {
@@ -361,6 +727,17 @@ mod tests {
             .build();
     }
 
+    // Unit Module declared at the crate root should use the crate name as its testing path.
+    #[test]
+    fn test_unit_crate_root() {
+        let module = testing::unit("mycrate")
+            .nonstatic()
+            .build();
+
+        assert_eq!("mycrate", module.namepath().testing_path(),
+            "Unit Module declared at the crate root should use the crate name as its testing path.");
+    }
+
     // Module namepath should be: `module_path!()`.
     #[test]
     fn test_namepath() {
@@ -370,6 +747,8 @@ mod tests {
 
         assert_eq!(module_path!(), module.namepath().path(),
             "Module namepath should be: `module_path!()`.");
+        assert_eq!(module.namepath().path(), module.namepath_str(),
+            "Module namepath_str() should be shorthand for `namepath().path()`.");
     }
 
     // Module use-case should match the fascade helper function that was used to create it.
@@ -387,12 +766,92 @@ mod tests {
             "Module use-case should match the fascade helper function (Benchmark) that was used to create it.");
     }
 
+    // Modules built from the same path and use-case should be the same, regardless of their temp dirs.
+    #[test]
+    fn test_same_as() {
+        let module_a = testing::unit(module_path!()).nonstatic().using_temp_dir().build();
+        let module_b = testing::unit(module_path!()).nonstatic().using_temp_dir().build();
+        let integration = testing::integration(module_path!()).nonstatic().build();
+
+        assert_ne!(module_a, module_b);
+        assert!(module_a.same_as(&module_b),
+            "Modules built from the same path and use-case should be the same, regardless of their temp dirs.");
+        assert!(!module_a.same_as(&integration),
+            "Modules built with different use-cases should not be the same.");
+    }
+
+    // Benchmark Module should report each benchmark recorded by its tests, in order.
+    #[test] #[named]
+    fn test_bench_report() {
+        let module = testing::benchmark(module_path!()).nonstatic().build();
+        let test_a = module.test(&format!("{}_a", function_name!())).build();
+        let test_b = module.test(&format!("{}_b", function_name!())).build();
+
+        test_a.bench(3, || std::thread::sleep(Duration::from_millis(1)));
+        test_b.bench(2, || {});
+
+        let report = module.bench_report();
+        let results = report.results();
+        assert_eq!(vec![test_a.namepath(), test_b.namepath()], results.iter().map(|r| r.namepath()).collect::<Vec<_>>(),
+            "Benchmark Module should report each benchmark recorded by its tests, in order.");
+        assert_eq!(3, results[0].iterations());
+        assert_eq!(2, results[1].iterations());
+        for result in results {
+            assert!(result.min() <= result.mean() && result.mean() <= result.max(),
+                "Benchmark Module should report sane timings.");
+        }
+        assert!(results[0].min() >= Duration::from_millis(1));
+
+        #[cfg(feature = "json")]
+        {
+            let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+            assert_eq!(test_b.namepath_str(), json[1]["namepath"]);
+            assert_eq!(2, json[1]["iterations"]);
+        }
+    }
+
+    // Non-benchmark Module should not report benchmarks.
+    #[test] #[should_panic]
+    fn test_bench_report_not_benchmark() {
+        testing::unit(module_path!()).nonstatic().build().bench_report();
+    }
+
+    // Module should report whether it was built as static.
+    #[test]
+    fn test_is_static() {
+        let unit = testing::unit(module_path!()).nonstatic().build();
+        assert!(!unit.is_static(), "Module built with `nonstatic()` should not be static.");
+        let unit = testing::unit(module_path!()).build();
+        assert!(unit.is_static(), "Module should be static by default.");
+    }
+
+    // Should register each static Module as it is built, but not non-static Modules.
+    #[test]
+    fn test_registered_modules() {
+        let alpha = testing::unit(&format!("{}::registered_alpha", module_path!())).build();
+        let bravo = testing::integration(&format!("{}::registered_bravo", module_path!())).build();
+        let nonstatic = testing::unit(&format!("{}::registered_nonstatic", module_path!())).nonstatic().build();
+
+        let registered = testing::registered_modules();
+        assert!(registered.contains(alpha.namepath()) && registered.contains(bravo.namepath()),
+            "Should register each static Module as it is built");
+        assert!(!registered.contains(nonstatic.namepath()),
+            "Should not register non-static Modules");
+    }
+
+    // Non-static Module should not create static Groups.
+    #[test] #[should_panic] #[named]
+    fn test_group_nonstatic() {
+        let unit = testing::unit(module_path!()).nonstatic().build();
+        unit.group(function_name!()).build();  // should panic
+    }
+
     // Module should construct Groups properly.
     // Groups constructed by Module should have a reference to it.
     #[test] #[named]
     fn test_group() {
         let unit = testing::unit(module_path!()).nonstatic().build();
-        let group = unit.group(function_name!()).build();
+        let group = unit.nonstatic_group(function_name!()).build();
 
         assert_eq!(function_name!(), group.name(),
             "Module should construct Groups properly.");
@@ -415,6 +874,44 @@ mod tests {
             "Module configured with `using_temp_dir()` should create the temp directory on construction.");
     }
 
+    // Module configured with `base_temp_dir_shared()` should have a base temp dir of:
+    //     { OS temp dir } / asmov_testing / { prefix }
+    // Module configured with `base_temp_dir_shared()` should only delete its own temp dir on teardown.
+    #[test] #[named]
+    fn test_temp_dir_shared() {
+        let namepath = namepath::join(module_path!(), function_name!());
+        let unit = testing::unit(&namepath).using_temp_dir().base_temp_dir_shared(function_name!()).nonstatic().build();
+        let expected_base_temp_dir = std::env::temp_dir()
+            .join(strings::SHARED_TEMP_DIR)
+            .join(function_name!())
+            .canonicalize()
+            .unwrap();
+
+        assert_eq!(expected_base_temp_dir, unit.base_temp_dir(),
+            "Module configured with `base_temp_dir_shared()` should have a base temp dir of: {{ OS temp dir }} / asmov_testing / {{ prefix }}");
+        assert!(unit.temp_dir().starts_with(&expected_base_temp_dir));
+
+        let temp_dir = unit.temp_dir().to_owned();
+        drop(unit);
+
+        assert!(!temp_dir.exists() && expected_base_temp_dir.exists(),
+            "Module configured with `base_temp_dir_shared()` should only delete its own temp dir on teardown.");
+        std::fs::remove_dir_all(&expected_base_temp_dir).unwrap();
+    }
+
+    // Module configured with `keep_temp_dirs()` should retain its base temp dir on teardown.
+    #[test] #[named]
+    fn test_temp_dir_kept() {
+        let namepath = namepath::join(module_path!(), function_name!());
+        let unit = testing::unit(&namepath).using_temp_dir().keep_temp_dirs().nonstatic().build();
+        let base_temp_dir = unit.base_temp_dir().to_owned();
+        drop(unit);
+
+        assert!(base_temp_dir.exists(),
+            "Module configured with `keep_temp_dirs()` should retain its base temp dir on teardown.");
+        std::fs::remove_dir_all(&base_temp_dir).unwrap();
+    }
+
     fn expected_unit_module_fixture_dir() -> PathBuf {
         PathBuf::from(strings::TESTING).join(strings::FIXTURES)
             .join(UseCase::Unit.to_str())
@@ -436,6 +933,77 @@ mod tests {
             "Module configured with `using_fixture_dir` should have a pre-existing fixture dir");
     }
 
+    // Module configured with `fixture_use_case()` should resolve fixtures under that use-case's directory.
+    // Module configured with `fixture_use_case()` should retain its own use-case.
+    #[test]
+    fn test_fixture_use_case() {
+        let unit = testing::unit("asmov_testing::typical")
+            .fixture_use_case(UseCase::Integration)
+            .using_fixture_dir()
+            .nonstatic()
+            .build();
+
+        let expected_fixture_dir = PathBuf::from(strings::TESTING).join(strings::FIXTURES)
+            .join(UseCase::Integration.to_str())
+            .join("typical")
+            .canonicalize()
+            .unwrap();
+
+        assert_eq!(expected_fixture_dir, unit.fixture_dir(),
+            "Module configured with `fixture_use_case()` should resolve fixtures under that use-case's directory.");
+        assert_eq!(UseCase::Unit, *unit.use_case(),
+            "Module configured with `fixture_use_case()` should retain its own use-case.");
+        assert_eq!(UseCase::Integration, *unit.fixture_use_case());
+    }
+
+    // Module configured with `using_fixture_dir` should hint at fixtures placed under the wrong use-case.
+    #[test] #[should_panic(expected = "which may be misplaced")]
+    fn test_fixture_dir_misplaced_use_case() {
+        testing::benchmark(module_path!())
+            .using_fixture_dir()
+            .nonstatic()
+            .build();
+    }
+
+    // Module configured with `using_fixture_dir` should suggest how to create a missing fixture dir.
+    #[test]
+    fn test_fixture_dir_missing() {
+        let result = std::panic::catch_unwind(|| {
+            testing::unit(&format!("{}::missing_fixtures", module_path!()))
+                .using_fixture_dir()
+                .nonstatic()
+                .build();
+        });
+
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        let expected_fixture_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("testing/fixtures/unit/module/tests/missing_fixtures");
+        assert!(message.contains(&format!("To create it, run: mkdir -p {}", expected_fixture_dir.to_str().unwrap())),
+            "Module configured with `using_fixture_dir` should anchor the suggested fixture dir at the crate: {}",
+            message);
+    }
+
+    // Module should build when each of its required fixture files exists.
+    #[test]
+    fn test_require_fixtures() {
+        let module = testing::unit(module_path!())
+            .require_fixtures(&["sample.txt"])
+            .nonstatic()
+            .build();
+
+        assert!(module.fixture_dir().join("sample.txt").is_file(),
+            "Module should build when each of its required fixture files exists");
+    }
+
+    // Module should name the full path of a missing required fixture file.
+    #[test] #[should_panic(expected = "testing/fixtures/unit/module/missing.json")]
+    fn test_require_fixtures_missing() {
+        testing::unit(module_path!())
+            .require_fixtures(&["sample.txt", "missing.json"])
+            .nonstatic()
+            .build();
+    }
+
     fn unit_module_namepath() -> Namepath {
         Namepath::module(UseCase::Unit, "asmov_testing::module".to_string())
     }
@@ -452,6 +1020,37 @@ mod tests {
             "Module should import external fixture dir");
     }
 
+    // Module should resolve imported fixture dirs under its fixture use-case, regardless of the order configured.
+    #[test]
+    fn test_import_fixture_dir_fixture_use_case() {
+        let namepath = Namepath::module(UseCase::Unit, "asmov_testing::typical".to_string());
+        let test_module = testing::unit(module_path!())
+            .import_fixture_dir(&namepath)
+            .fixture_use_case(UseCase::Integration)
+            .nonstatic()
+            .build();
+
+        let expected_fixture_dir = PathBuf::from(strings::TESTING).join(strings::FIXTURES)
+            .join(UseCase::Integration.to_str())
+            .join("typical")
+            .canonicalize()
+            .unwrap();
+
+        assert_eq!(expected_fixture_dir, test_module.imported_fixture_dir(&namepath),
+            "Module should resolve imported fixture dirs under its fixture use-case, regardless of the order configured.");
+    }
+
+    // Module should list the imported namepaths when a lookup misses.
+    #[test] #[should_panic(expected = "(Available: asmov_testing::module)")]
+    fn test_import_fixture_dir_fail_available() {
+        let test_module = testing::unit(module_path!())
+            .import_fixture_dir(&unit_module_namepath())
+            .nonstatic()
+            .build();
+
+        test_module.imported_fixture_dir(&Namepath::module(UseCase::Unit, "asmov_testing::Module".to_string()));
+    }
+
     #[test] #[should_panic]
     fn test_import_fixture_dir_fail() {
         let namepath = unit_module_namepath();
@@ -506,6 +1105,48 @@ mod tests {
             .build();
     }
 
+    // Should prune aged base temp dirs matching the module prefix, while leaving fresh and unrelated dirs alone.
+    #[test] #[named]
+    fn test_prune_orphaned_temp_dirs() {
+        let base_dir = std::env::temp_dir()
+            .join(namepath::squash(&concat!(module_path!(), function_name!())));
+
+        if base_dir.exists() {
+            std::fs::remove_dir_all(&base_dir).unwrap();
+        }
+        std::fs::create_dir(&base_dir).unwrap(); // needs manual teardown
+
+        const PREFIX: &str = "asmov_testing_module_tests";
+        let make_dir = |name: &str| {
+            let dir = base_dir.join(name);
+            std::fs::create_dir(&dir).unwrap();
+            dir
+        };
+
+        let orphaned = make_dir(&format!("{PREFIX}.aB3dE6gH"));
+        let orphaned_too = make_dir(&format!("{PREFIX}.12345678"));
+        let unrelated = make_dir("other_module.aB3dE6gH");
+        let not_random = make_dir(&format!("{PREFIX}.keep"));
+
+        // rather than aging the dirs, which isn't portable, vary the max age
+        let pruned = testing::Module::prune_orphaned_temp_dirs(&base_dir, PREFIX, Duration::from_secs(3600));
+        assert_eq!(0, pruned,
+            "Should not prune base temp dirs that are younger than the max age");
+        assert!(orphaned.exists() && orphaned_too.exists(),
+            "Should not prune base temp dirs that are younger than the max age");
+
+        std::thread::sleep(Duration::from_millis(10));
+        let pruned = testing::Module::prune_orphaned_temp_dirs(&base_dir, PREFIX, Duration::ZERO);
+        assert_eq!(2, pruned,
+            "Should report the number of pruned dirs");
+        assert!(!orphaned.exists() && !orphaned_too.exists(),
+            "Should prune aged base temp dirs matching the module prefix");
+        assert!(unrelated.exists() && not_random.exists(),
+            "Should not prune dirs that were not created for the module");
+
+        std::fs::remove_dir_all(base_dir).unwrap(); // testing cleanup
+    }
+
     #[test]
     // Should teardown temp directories
     fn test_teardown() {
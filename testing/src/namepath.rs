@@ -1,4 +1,5 @@
 use std::{path::{PathBuf, Path}, hash::Hash};
+use anyhow::bail;
 use crate::{Module, Group, UseCase};
 
 pub trait NamepathTrait {
@@ -14,10 +15,28 @@ pub trait NamepathTrait {
         PathBuf::from_iter(split(&self.path()))
     }
 
+    /// The directory heirarchy of this namepath, relative to a use-case's testing directory. The crate name and
+    /// any `tests` module suffix are stripped. E.g., `mycrate::submod::tests::my_test` -> `submod/my_test`
     fn testing_dir(&self) -> PathBuf {
         PathBuf::from_iter(split(&self.testing_path()))
     }
 
+    /// The fixture path of this namepath for a use-case, relative to the crate being tested and not canonicalized,
+    /// so that it can be displayed whether or not it exists.
+    /// 
+    /// path: testing / fixtures / [ unit | integration | benchmark ] / { testing dir }
+    fn fixture_path(&self, use_case: UseCase) -> PathBuf {
+        PathBuf::from(crate::strings::TESTING)
+            .join(crate::strings::FIXTURES)
+            .join(use_case.to_str())
+            .join(self.testing_dir())
+    }
+
+    /// Same as `dir()`, with each component sanitized of characters that are reserved on any platform.
+    fn safe_dir(&self) -> PathBuf {
+        PathBuf::from_iter(self.components().iter().map(|component| sanitize(component)))
+    }
+
     fn squash(&self) -> String {
         squash(self.path())
     }
@@ -51,20 +70,22 @@ impl NamepathTrait for ModuleNamepath {
 }
 
 // Strips the crate name prefix and the test/tests suffix from a module_path!().
-// If the path is from lib.rs, the crate name is returned. 
+// The crate name is returned if the path is from the crate root. E.g., a unit test declared in `lib.rs`
 fn make_testing_path(use_case: UseCase, path: &str) -> Option<&str> {
-    static REGEX_INTEGRATION: once_cell::sync::OnceCell<regex::Regex> = once_cell::sync::OnceCell::new();
-    static REGEX_UNIT: once_cell::sync::OnceCell<regex::Regex> = once_cell::sync::OnceCell::new();
-    let regex_integration = REGEX_INTEGRATION.get_or_init(|| {
-        regex::Regex::new(r"^(.+?)(?:::tests)?$").unwrap()
+    static REGEX_CRATE_ROOT: once_cell::sync::OnceCell<regex::Regex> = once_cell::sync::OnceCell::new();
+    static REGEX_NESTED: once_cell::sync::OnceCell<regex::Regex> = once_cell::sync::OnceCell::new();
+    let regex_crate_root = REGEX_CRATE_ROOT.get_or_init(|| {
+        regex::Regex::new(r"^(\w+?)(?:::tests)?$").unwrap()
     });
-    let regex_unit= REGEX_UNIT.get_or_init(|| {
+    let regex_nested = REGEX_NESTED.get_or_init(|| {
         regex::Regex::new(r"^\w+::(.+?)(?:::tests)?$").unwrap()
     });
 
     let captures = match use_case {
-        UseCase::Integration | UseCase::Benchmark => regex_integration.captures(path),
-        UseCase::Unit => regex_unit.captures(path)
+        UseCase::Integration | UseCase::Benchmark => regex_crate_root.captures(path)
+            .or_else(|| regex_nested.captures(path)),
+        UseCase::Unit => regex_nested.captures(path)
+            .or_else(|| regex_crate_root.captures(path)),
     };
 
     match captures {
@@ -75,13 +96,19 @@ fn make_testing_path(use_case: UseCase, path: &str) -> Option<&str> {
 
 impl ModuleNamepath {
     pub fn new(use_case: UseCase, module_path: String) -> Self {
-        let testing_path = make_testing_path(use_case, &module_path)
-            .expect(&format!("Unable to form namepath from {} module path: {module_path}", use_case.to_str()));
+        Self::try_new(use_case, module_path).unwrap()
+    }
 
-        Self {
-            testing_path: String::from(testing_path),
+    pub fn try_new(use_case: UseCase, module_path: String) -> anyhow::Result<Self> {
+        let testing_path = match make_testing_path(use_case, &module_path) {
+            Some(testing_path) => String::from(testing_path),
+            None => bail!("Unable to form namepath from {} module path: {module_path}", use_case.to_str())
+        };
+
+        Ok(Self {
+            testing_path,
             module_path
-        }
+        })
     }
 }
 
@@ -199,6 +226,11 @@ impl Namepath {
         Self::Module(ModuleNamepath::new(use_case, module_path))
     }
 
+    /// Forms a module namepath, or an error if `module_path` is not a valid `module_path!()`.
+    pub fn try_module(use_case: UseCase, module_path: String) -> anyhow::Result<Self> {
+        Ok(Self::Module(ModuleNamepath::try_new(use_case, module_path)?))
+    }
+
     pub fn group(module: &Module, name: String) -> Self {
         Self::Group(GroupNamepath::new(module, name))
     }
@@ -206,6 +238,19 @@ impl Namepath {
     pub fn test(module: &Module, group: Option<&Group>, name: String) -> Self {
         Self::Test(TestNamepath::new(module, group, name))
     }
+
+    /// Returns the trailing components of this namepath that follow `ancestor`, if `ancestor` is a prefix of it.
+    /// Identical namepaths produce an empty list.
+    pub fn relative_to(&self, ancestor: &Namepath) -> Option<Vec<&str>> {
+        let components = self.components();
+        let ancestor_components = ancestor.components();
+
+        if components.starts_with(&ancestor_components) {
+            Some(components[ancestor_components.len()..].to_vec())
+        } else {
+            None
+        }
+    }
 }
 
 impl NamepathTrait for Namepath {
@@ -253,6 +298,27 @@ impl NamepathTrait for Namepath {
 mod strings {
     pub const SEPARATOR: &str = "::";
     pub const SQUASH_SEPARATOR: &str = "_";
+    pub const FORBIDDEN_NAME_TOKENS: [&str; 3] = [SEPARATOR, "/", "."];
+    pub const RESERVED_PATH_CHARS: [char; 9] = ['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
+    pub const SANITIZED_CHAR: char = '_';
+}
+
+// Ensures that a group or test name is a single token, free of namepath and path delimiters
+pub(crate) fn validate_name_token(name: &str) -> anyhow::Result<()> {
+    for delimiter in strings::FORBIDDEN_NAME_TOKENS {
+        if name.contains(delimiter) {
+            bail!("Name `{name}` contains a forbidden delimiter: `{delimiter}`");
+        }
+    }
+
+    Ok(())
+}
+
+// Replaces characters that are reserved in file names on any platform (E.g., Windows) with an underscore
+pub fn sanitize(component: &str) -> String {
+    component.chars()
+        .map(|c| if c.is_control() || strings::RESERVED_PATH_CHARS.contains(&c) { strings::SANITIZED_CHAR } else { c })
+        .collect()
 }
 
 // Splits a namepath by its delimiters
@@ -346,4 +412,101 @@ mod tests {
         assert_eq!(EXPECTED, join_three(INPUT_FIRST, INPUT_SECOND, INPUT_THIRD),
             "Should join two strings using '::'.");
     }
-}
\ No newline at end of file
+
+    // Should reject names containing each forbidden delimiter.
+    #[test]
+    fn test_validate_name_token() {
+        assert!(validate_name_token("test_foo").is_ok(),
+            "Should accept a single non-delimited token.");
+
+        for name in ["foo::bar", "foo/bar", "foo.bar"] {
+            assert!(validate_name_token(name).is_err(),
+                "Should reject names containing each forbidden delimiter.");
+        }
+    }
+
+    // Should replace reserved characters in each component of a directory.
+    #[test]
+    fn test_safe_dir() {
+        let namepath = Namepath::module(UseCase::Unit, "foo::b:a?r::j*ar".to_string());
+        let expected = PathBuf::from("foo").join("b_a_r").join("j_ar");
+
+        assert_eq!(expected, namepath.safe_dir(),
+            "Should replace reserved characters in each component of a directory.");
+        assert_eq!(PathBuf::from("foo").join("bar"), Namepath::module(UseCase::Unit, "foo::bar".to_string()).safe_dir(),
+            "Should leave portable components unaltered.");
+    }
+
+    // Should return the trailing components of a namepath relative to an ancestor prefix.
+    #[test]
+    fn test_relative_to() {
+        let ancestor = Namepath::module(UseCase::Unit, "foo::bar".to_string());
+        let child = Namepath::module(UseCase::Unit, "foo::bar::jar::car".to_string());
+        let other = Namepath::module(UseCase::Unit, "foo::barn::jar".to_string());
+
+        assert_eq!(Some(vec!["jar", "car"]), child.relative_to(&ancestor),
+            "Should return the trailing components of a namepath relative to an ancestor prefix.");
+        assert_eq!(None, other.relative_to(&ancestor),
+            "Should return nothing when the ancestor is not a prefix.");
+        assert_eq!(None, ancestor.relative_to(&child),
+            "Should return nothing when the ancestor is a descendant.");
+        assert_eq!(Some(Vec::<&str>::new()), ancestor.relative_to(&ancestor.clone()),
+            "Should return no components for identical namepaths.");
+    }
+
+    // Should construct the fixture path of a module namepath without canonicalizing it.
+    #[test]
+    fn test_fixture_path_module() {
+        let namepath = Namepath::module(UseCase::Unit, "mycrate::submod::tests".to_string());
+        let expected = PathBuf::from("testing").join("fixtures").join("unit").join("submod");
+
+        assert_eq!(expected, namepath.fixture_path(UseCase::Unit),
+            "Should construct the fixture path of a module namepath without canonicalizing it.");
+        assert_eq!(PathBuf::from("testing").join("fixtures").join("integration").join("submod"),
+            namepath.fixture_path(UseCase::Integration),
+            "Should place the fixture path under the requested use-case.");
+    }
+
+    // Should construct the fixture path of a group namepath, even if it does not exist.
+    #[test]
+    fn test_fixture_path_group() {
+        let module = crate::unit("mycrate::submod::tests").nonstatic().build();
+        let namepath = Namepath::group(&module, "missing_group".to_string());
+        let expected = PathBuf::from("testing").join("fixtures").join("unit").join("submod").join("missing_group");
+
+        assert_eq!(expected, namepath.fixture_path(UseCase::Unit),
+            "Should construct the fixture path of a group namepath, even if it does not exist.");
+    }
+
+    // Should strip the crate root and the tests suffix for each use-case.
+    #[test]
+    fn test_make_testing_path() {
+        for use_case in [UseCase::Integration, UseCase::Benchmark] {
+            assert_eq!(Some("mycrate"), make_testing_path(use_case, "mycrate"),
+                "Should use the crate name for crate-root modules.");
+            assert_eq!(Some("mycrate"), make_testing_path(use_case, "mycrate::tests"),
+                "Should use the crate name for crate-root test modules.");
+            assert_eq!(Some("submod"), make_testing_path(use_case, "mycrate::submod"),
+                "Should strip the crate root from nested modules.");
+            assert_eq!(Some("submod"), make_testing_path(use_case, "mycrate::submod::tests"),
+                "Should strip the crate root and tests suffix from nested test modules.");
+            assert_eq!(Some("submod::inner"), make_testing_path(use_case, "mycrate::submod::inner::tests"),
+                "Should preserve deeply nested modules.");
+        }
+
+        assert_eq!(Some("submod"), make_testing_path(UseCase::Unit, "mycrate::submod::tests"),
+            "Should strip the crate root and tests suffix from unit test modules.");
+        assert_eq!(Some("mycrate"), make_testing_path(UseCase::Unit, "mycrate"),
+            "Should use the crate name for crate-root unit test modules.");
+    }
+
+    // Should return an error rather than panic for an invalid module path.
+    #[test]
+    fn test_try_module() {
+        assert!(Namepath::try_module(UseCase::Unit, "mycrate::tests".to_string()).is_ok());
+
+        let err = Namepath::try_module(UseCase::Unit, "my crate".to_string()).unwrap_err();
+        assert_eq!("Unable to form namepath from unit module path: my crate", err.to_string(),
+            "Should return an error rather than panic for an invalid module path.");
+    }
+}
@@ -1,6 +1,6 @@
-use std::{collections::HashMap, path::{PathBuf, Path}};
+use std::{collections::HashMap, panic, path::{PathBuf, Path}, time::Instant};
 use anyhow::Context;
-use crate::{Group, Module, Namepath, Testable};
+use crate::{Group, Module, ModuleBuilder, Namepath, NamepathTrait, Testable, UseCase, bench::BenchResult};
 
 pub enum Parent<'module,'group,'grpfunc> {
     Module(&'module Module),
@@ -44,6 +44,13 @@ impl<'module,'group,'grpfnc> Testable for Parent<'group,'module,'grpfnc> {
             Self::Group(group) => group.imported_fixture_dir(namepath)
         }
     }
+
+    fn imported_fixture_path(&self, key: &str) -> &Path {
+        match &self {
+            Self::Module(module) => module.imported_fixture_path(key),
+            Self::Group(group) => group.imported_fixture_path(key)
+        }
+    }
 }
 
 /// A single test that can be ran.
@@ -55,15 +62,50 @@ pub struct Test<'module,'group,'grpfunc,'func> {
     pub(crate) temp_dir: Option<PathBuf>,
     pub(crate) fixture_dir: Option<PathBuf>,
     pub(crate) imported_fixture_dirs: Option<HashMap<Namepath, PathBuf>>,
+    pub(crate) imported_fixture_paths: Option<HashMap<String, PathBuf>>,
     pub(crate) teardown_func: Option<Box<dyn FnOnce(&mut Test) + 'func>>,
+    pub(crate) keep_temp_dir_on_failure: bool,
+    pub(crate) failed: bool,
 }
 
 impl<'module,'group,'grpfunc,'func> Test<'module,'group,'grpfunc,'func> {
+    /// Runs the test body, catching a panic so that teardown completes and the outcome is logged before unwinding
+    /// resumes. A panicking body is re-raised afterwards, failing the test as usual.
+    pub fn run(mut self, body: impl FnOnce(&Test)) {
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| body(&self)));
+        self.failed = result.is_err();
+        self.teardown();
+
+        match result {
+            Ok(_) => eprintln!("Test passed: {}", self.namepath_str()),
+            Err(payload) => {
+                eprintln!("Test failed: {}", self.namepath_str());
+                panic::resume_unwind(payload);
+            }
+        }
+    }
+
     /// The full namepath of this test, including its parent Module and Group.
     pub fn namepath(&self) -> &Namepath {
         &self.namepath
     }
 
+    /// Shorthand for `namepath().path()`.
+    pub fn namepath_str(&self) -> &str {
+        self.namepath.path()
+    }
+
+    /// The namepaths of this test's lineage, in order: [ module, group (if any), test ]
+    pub fn ancestry(&self) -> Vec<&Namepath> {
+        let mut ancestry = match self.group {
+            Some(group) => group.ancestry(),
+            None => vec![self.module.namepath()]
+        };
+
+        ancestry.push(&self.namepath);
+        ancestry
+    }
+
     /// The name of this test. Usually this is the function name that created it.
     pub fn name(&self) -> &str {
         match &self.namepath {
@@ -90,6 +132,22 @@ impl<'module,'group,'grpfunc,'func> Test<'module,'group,'grpfunc,'func> {
         }
     }
 
+    /// Times each iteration of `func`, recording the result with the parent benchmark Module for its `bench_report()`.
+    pub fn bench(&self, iterations: usize, mut func: impl FnMut()) -> BenchResult {
+        assert_eq!(UseCase::Benchmark, *self.module.use_case(), "Test must belong to a benchmark Module to bench");
+        let timings: Vec<_> = (0..iterations)
+            .map(|_| {
+                let start = Instant::now();
+                func();
+                start.elapsed()
+            })
+            .collect();
+
+        let result = BenchResult::new(self.namepath.clone(), &timings);
+        self.module.bench_results.record(result.clone());
+        result
+    }
+
     /// The temporary directory for this test. It is created upon building of the test and deleted upon destruction.
     pub fn temp_dir(&self) -> &Path {
         &self.temp_dir.as_ref().context("Test `temp dir` is not configured").unwrap()
@@ -107,16 +165,42 @@ impl<'module,'group,'grpfunc,'func> Test<'module,'group,'grpfunc,'func> {
             None => self.module.try_imported_fixture_dir(namepath)
         };
 
-        parent_result.context("Test: `imported fixture dirs` is not configured")
+        parent_result.with_context(|| format!("Test: {}",
+            crate::imported_fixture_dir_not_found(namepath, self.imported_fixture_dirs.as_ref())))
+    }
+
+    pub(crate) fn try_imported_fixture_path(&self, key: &str) -> anyhow::Result<&Path> {
+        if let Some(imported_fixture_paths) = self.imported_fixture_paths.as_ref() {
+            if let Some(dir) = imported_fixture_paths.get(key) {
+                return Ok(dir.as_path());
+            }
+        }
+
+        let parent_result = match self.group {
+            Some(group) => group.try_imported_fixture_path(key),
+            None => self.module.try_imported_fixture_path(key)
+        };
+
+        parent_result.context("Test: `imported fixture paths` is not configured")
     }
 
     fn teardown(&mut self) {
         if let Some(teardown_fn) = self.teardown_func.take() {
-            teardown_fn(self);
+            if self.module.teardown_catch_panics {
+                let module = self.module;
+                let namepath = self.namepath.clone();
+                module.teardown_panics.catch(&namepath, || teardown_fn(self));
+            } else {
+                teardown_fn(self);
+            }
         }
 
         if let Some(dir) = self.temp_dir.take() {
-            if dir.exists() && std::fs::remove_dir_all(&dir).is_err() {
+            // a panic during drop, or one caught by run(), means that the test has failed
+            let failed = self.failed || std::thread::panicking();
+            if (self.keep_temp_dir_on_failure || self.module.keep_temp_dirs) && failed {
+                eprintln!("Retaining temp dir of failed test: {}", dir.to_str().unwrap());
+            } else if dir.exists() && std::fs::remove_dir_all(&dir).is_err() {
                 eprintln!("Unable to delete temp dir: {}", dir.to_str().unwrap());
             }
         }
@@ -133,6 +217,10 @@ impl<'module,'group,'grpfunc,'func> Testable for Test<'module,'group,'grpfunc,'f
     fn imported_fixture_dir(&self, namepath: &Namepath) -> &Path {
         self.try_imported_fixture_dir(namepath).unwrap()
     }
+
+    fn imported_fixture_path(&self, key: &str) -> &Path {
+        self.try_imported_fixture_path(key).unwrap()
+    }
 }
 
 impl<'module,'group,'grpfunc,'func> Drop for Test<'module,'group,'grpfunc,'func> {
@@ -148,18 +236,24 @@ pub struct TestBuilder<'module,'group,'grpfunc,'func> {
     pub(crate) group: Option<&'group Group<'module,'grpfunc>>,
     pub(crate) using_temp_dir: bool,
     pub(crate) inherit_temp_dir: bool,
+    pub(crate) inherit_temp_dir_scoped: bool,
     pub(crate) using_fixture_dir: bool,
+    pub(crate) fixture_dir_case: Option<String>,
     pub(crate) inherit_fixture_dir: bool,
+    pub(crate) inherit_fixture_dir_or_module: bool,
     pub(crate) imported_fixture_dirs: Option<HashMap<Namepath, PathBuf>>,
+    pub(crate) imported_fixture_paths: Option<HashMap<String, PathBuf>>,
     pub(crate) setup_func: Option<Box<dyn FnOnce(&mut Test) + 'func>>,
     pub(crate) teardown_func: Option<Box<dyn FnOnce(&mut Test) + 'func>>,
+    pub(crate) keep_temp_dir_on_failure: bool,
 }
 
 impl<'module,'group,'grpfunc,'func>
 TestBuilder<'module,'group,'grpfunc,'func> {
     pub(crate) fn new(module: &'module Module, group: Option<&'group Group<'module,'grpfunc>>, name: &str) -> Self{
-        debug_assert!(!name.contains("::") && !name.contains('/') && !name.contains('.'),
-            "Test name should be a single non-delimited token.");
+        crate::namepath::validate_name_token(name)
+            .context("Test name should be a single non-delimited token")
+            .unwrap();
 
         Self {
             name: name.to_owned(),
@@ -167,39 +261,77 @@ TestBuilder<'module,'group,'grpfunc,'func> {
             group,
             using_temp_dir: false,
             inherit_temp_dir: false,
+            inherit_temp_dir_scoped: false,
             using_fixture_dir: false,
+            fixture_dir_case: None,
             inherit_fixture_dir: false,
+            inherit_fixture_dir_or_module: false,
             imported_fixture_dirs: None,
+            imported_fixture_paths: None,
             setup_func: None,
             teardown_func: None,
+            keep_temp_dir_on_failure: false,
         }
     }
 
     /// Builds the test and initializes it.
     pub fn build(self) -> Test<'module,'group,'grpfunc,'func> {
         let namepath = Namepath::test(&self.module, self.group, self.name);
+        self.module.test_namepaths.record(&namepath);
+
+        // module defaults apply only when the test made no choice of its own
+        let using_temp_dir = self.using_temp_dir || (self.module.default_test_temp_dir
+            && !self.inherit_temp_dir && !self.inherit_temp_dir_scoped);
+        let using_fixture_dir = self.using_fixture_dir || (self.module.default_test_fixture_dir
+            && !self.inherit_fixture_dir && !self.inherit_fixture_dir_or_module);
 
-        let temp_dir = if self.using_temp_dir {
+        let temp_dir = if using_temp_dir {
             Some(crate::build_temp_dir(&namepath, &self.module.base_temp_dir()))
-        } else if self.inherit_temp_dir {
-            Some(match self.group {
+        } else if self.inherit_temp_dir || self.inherit_temp_dir_scoped {
+            let parent_temp_dir = match self.group {
                 Some(group) => group.temp_dir().to_owned(),
-                None => self.module.temp_dir().to_owned() })
+                None => self.module.temp_dir().to_owned() };
+
+            if self.inherit_temp_dir_scoped {
+                Some(ModuleBuilder::create_random_subdir(&parent_temp_dir, namepath.components().last().unwrap())
+                    .context(format!("Unable to create scoped temporary directory in: {}", parent_temp_dir.to_str().unwrap()))
+                    .unwrap())
+            } else {
+                Some(parent_temp_dir)
+            }
         } else {
             None
         };
 
-        let fixture_dir = if self.using_fixture_dir {
-            Some(crate::build_fixture_dir(&namepath, self.module.use_case))
+        let fixture_dir = if let Some(case) = &self.fixture_dir_case {
+            let case_dir = crate::build_fixture_dir(&namepath, self.module.fixture_use_case).join(case);
+            Some(case_dir.canonicalize()
+                .context(format!("Test fixture dir for case `{}` does not exist: {}", case, case_dir.to_str().unwrap()))
+                .unwrap())
+        } else if using_fixture_dir {
+            Some(crate::build_fixture_dir(&namepath, self.module.fixture_use_case))
         } else if self.inherit_fixture_dir {
             Some( match self.group {
                 Some(group) => group.fixture_dir().to_owned(),
                 None => self.module.fixture_dir().to_owned() })
+        } else if self.inherit_fixture_dir_or_module {
+            Some( match self.group {
+                Some(group) if group.fixture_dir.is_some() => group.fixture_dir().to_owned(),
+                _ => self.module.fixture_dir().to_owned() })
         } else {
             None
         };
 
-        let imported_fixture_dirs = self.imported_fixture_dirs;
+        // the group's imports are copied in beneath the test's own, which take precedence
+        let imported_fixture_dirs = match self.group.and_then(|group| group.imported_fixture_dirs.as_ref()) {
+            Some(group_dirs) => {
+                let mut dirs = group_dirs.clone();
+                dirs.extend(self.imported_fixture_dirs.unwrap_or_default());
+                Some(dirs)
+            },
+            None => self.imported_fixture_dirs
+        };
+        let imported_fixture_paths = self.imported_fixture_paths;
 
         let mut test = Test {
             module: self.module,
@@ -208,7 +340,10 @@ TestBuilder<'module,'group,'grpfunc,'func> {
             temp_dir,
             fixture_dir,
             imported_fixture_dirs,
+            imported_fixture_paths,
             teardown_func: self.teardown_func,
+            keep_temp_dir_on_failure: self.keep_temp_dir_on_failure,
+            failed: false,
         };
 
         if let Some(setup_fn) = self.setup_func {
@@ -221,13 +356,29 @@ TestBuilder<'module,'group,'grpfunc,'func> {
     /// Configures this test to use an existing fixture directory.
     /// The base path is defined by the parent Module or Group, with an existing subdirectory expected to be the name of this test.
     pub fn using_fixture_dir(mut self) -> Self {
-        assert!(!self.inherit_fixture_dir, "Configuring both `inherit` and `using` for `fixture_dir` is ambiguous");
+        assert!(!self.inherit_fixture_dir && !self.inherit_fixture_dir_or_module,
+            "Configuring both `inherit` and `using` for `fixture_dir` is ambiguous");
         self.using_fixture_dir = true;
         self
     }
 
+    /// Configures this test to use a subdirectory of its fixture directory for a single case of a parameterized test:
+    /// `{ test fixture dir } / { case }`. Each case is expected to exist.
+    pub fn using_fixture_dir_case(mut self, case: &str) -> Self {
+        crate::namepath::validate_name_token(case)
+            .context("Fixture dir case should be a single non-delimited token")
+            .unwrap();
+
+        self = self.using_fixture_dir();
+        self.fixture_dir_case = Some(case.to_owned());
+        self
+    }
+
+    /// Imports the fixture directory of another Module, Group, or Test, retrievable with
+    /// [imported_fixture_dir()](Testable::imported_fixture_dir).
+    /// A test built from a Group also inherits copies of the Group's imported fixture dirs, which this overrides.
     pub fn import_fixture_dir(mut self, namepath: &Namepath) -> Self {
-        let dir = crate::build_fixture_dir(&namepath, self.module.use_case);
+        let dir = crate::build_fixture_dir(&namepath, self.module.fixture_use_case);
         let dir = dir.canonicalize()
             .context(format!("Imported fixture dir does not exist: {}", &dir.to_str().unwrap()))
             .unwrap();
@@ -242,10 +393,25 @@ TestBuilder<'module,'group,'grpfunc,'func> {
         self
     }
 
+    /// Imports an arbitrary existing directory under `key`, bypassing namepath resolution.
+    /// Retrievable with [imported_fixture_path()](Testable::imported_fixture_path).
+    pub fn import_fixture_path(mut self, key: &str, path: impl AsRef<Path>) -> Self {
+        let dir = crate::build_imported_fixture_path(path.as_ref());
+
+        if self.imported_fixture_paths.is_none() {
+            self.imported_fixture_paths = Some(HashMap::new());
+        }
+
+        self.imported_fixture_paths.as_mut().expect("Option should exist")
+            .insert(key.to_owned(), dir);
+
+        self
+    }
+
     /// Configures the test to use a temporary directory.  
     /// The base path is defined by the parent Module or Group, with a subdirectory created just for this test (by its name).  
     pub fn using_temp_dir(mut self) -> Self {
-        assert!(!self.inherit_temp_dir);
+        assert!(!self.inherit_temp_dir && !self.inherit_temp_dir_scoped);
         if self.module.temp_dir.is_none() {
             panic!("Test cannot use a temporary directory unless its parent Module uses one");
         } else if let Some(group) = self.group {
@@ -261,7 +427,7 @@ TestBuilder<'module,'group,'grpfunc,'func> {
     /// Configures the test to use the exact same temporary directory as its parent Module or Group.
     /// A separate subdirectory will not be created for this test.
     pub fn inherit_temp_dir(mut self) -> Self {
-        assert!(!self.using_temp_dir);
+        assert!(!self.using_temp_dir && !self.inherit_temp_dir_scoped);
         if self.module.temp_dir.is_none() {
             panic!("Test cannot use a temporary directory unless its parent Module uses one");
         } else if let Some(group) = self.group {
@@ -274,14 +440,47 @@ TestBuilder<'module,'group,'grpfunc,'func> {
         self
     }
 
+    /// Configures the test to use a uniquely named subdirectory of its parent Module or Group's temporary directory:
+    /// `{ parent temp dir } / { test name }.{ random chars }`. Unlike `inherit_temp_dir()`, tests sharing a parent
+    /// are isolated from each other. The subdirectory is deleted upon destruction of the test.
+    pub fn inherit_temp_dir_scoped(mut self) -> Self {
+        assert!(!self.using_temp_dir && !self.inherit_temp_dir);
+        if self.module.temp_dir.is_none() {
+            panic!("Test cannot use a temporary directory unless its parent Module uses one");
+        } else if let Some(group) = self.group {
+            if group.temp_dir.is_none() {
+                panic!("Test cannot inherit a temporary directory unless its parent Group uses one");
+            }
+        }
+
+        self.inherit_temp_dir_scoped = true;
+        self
+    }
+
+    /// For debugging. Retains this test's temp dir if the test fails (panics), printing its path, rather than deleting it.
+    /// Note that the parent Module's base temp dir is still deleted on teardown, unless it was built with
+    /// `keep_temp_dirs()`.
+    pub fn keep_temp_dir_on_failure(mut self) -> Self {
+        self.keep_temp_dir_on_failure = true;
+        self
+    }
+
     /// Configures the test to use the exact same fixture directory as its parent Module or Group.
     /// A separate subdirectory for this test is not expected to exist.
     pub fn inherit_fixture_dir(mut self) -> Self {
-        assert!(!self.using_fixture_dir);
+        assert!(!self.using_fixture_dir && !self.inherit_fixture_dir_or_module);
         self.inherit_fixture_dir = true;
         self
     }
 
+    /// Configures the test to use the same fixture directory as its parent Group, if it has one configured.
+    /// Otherwise, falls back to that of its parent Module.
+    pub fn inherit_fixture_dir_or_module(mut self) -> Self {
+        assert!(!self.using_fixture_dir && !self.inherit_fixture_dir);
+        self.inherit_fixture_dir_or_module = true;
+        self
+    }
+
     /// Calls the provided function once on construction of the test.
     pub fn setup(mut self, func: impl FnOnce(&mut Test) + 'func) -> Self {
         self.setup_func = Some(Box::new(func));
@@ -298,6 +497,8 @@ TestBuilder<'module,'group,'grpfunc,'func> {
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
+    #[cfg(any(feature = "json", feature = "toml"))]
+    use std::collections::HashMap;
     use crate::{self as testing, prelude::*, NamepathTrait, Test, Namepath, UseCase};
 
     static MODULE_BASIC: testing::StaticModule = testing::module(|| {
@@ -369,6 +570,183 @@ mod tests {
 
         assert_eq!(expected_namepath_group, test.namepath().path(),
             "Test with a parent Group should have a namepath of: `Test::group().namepath()` / `Test::name()`");
+        assert_eq!(test.namepath().path(), test.namepath_str(),
+            "Test namepath_str() should be shorthand for `namepath().path()`.");
+    }
+
+    // Test ancestry should list the namepaths of its Module, Group (if any), and itself, in order.
+    #[test] #[named]
+    fn test_ancestry() {
+        let test = MODULE_BASIC.test(function_name!()).build();
+        assert_eq!(vec![MODULE_BASIC.namepath(), test.namepath()], test.ancestry(),
+            "Test ancestry should list the namepaths of its Module, Group (if any), and itself, in order.");
+
+        let test = GROUP_BASIC.test(function_name!()).build();
+        assert_eq!(vec![MODULE_WITH_DIRS.namepath(), GROUP_BASIC.namepath(), test.namepath()], test.ancestry(),
+            "Test ancestry should list the namepaths of its Module, Group (if any), and itself, in order.");
+    }
+
+    // Test configured with `keep_temp_dir_on_failure()` should retain its temp dir when it panics.
+    #[test] #[named]
+    fn test_temp_dir_kept_on_failure() {
+        let mut temp_dir = None;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let test = MODULE_WITH_DIRS.test(function_name!())
+                .using_temp_dir()
+                .keep_temp_dir_on_failure()
+                .build();
+
+            temp_dir = Some(test.temp_dir().to_owned());
+            panic!("Intentional test failure");
+        }));
+
+        assert!(result.is_err());
+        let temp_dir = temp_dir.unwrap();
+        assert!(temp_dir.exists(),
+            "Test configured with `keep_temp_dir_on_failure()` should retain its temp dir when it panics.");
+        std::fs::remove_dir_all(&temp_dir).unwrap();
+
+        let test = MODULE_WITH_DIRS.test(function_name!())
+            .using_temp_dir()
+            .keep_temp_dir_on_failure()
+            .build();
+        let temp_dir = test.temp_dir().to_owned();
+        drop(test);
+        assert!(!temp_dir.exists(),
+            "Test configured with `keep_temp_dir_on_failure()` should delete its temp dir when it passes.");
+    }
+
+    // Should run teardown after a passing body.
+    #[test] #[named]
+    fn test_run_passed() {
+        let torn_down = std::cell::Cell::new(false);
+        let mut temp_dir = None;
+        MODULE_WITH_DIRS.test(function_name!())
+            .using_temp_dir()
+            .teardown(|_| torn_down.set(true))
+            .build()
+            .run(|test| {
+                temp_dir = Some(test.temp_dir().to_owned());
+            });
+
+        assert!(torn_down.get() && !temp_dir.unwrap().exists(),
+            "Should run teardown after a passing body.");
+    }
+
+    // Should run teardown after a panicking body and then re-raise the panic.
+    #[test] #[named]
+    fn test_run_failed() {
+        let torn_down = std::cell::Cell::new(false);
+        let mut temp_dir = None;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            MODULE_WITH_DIRS.test(function_name!())
+                .using_temp_dir()
+                .keep_temp_dir_on_failure()
+                .teardown(|_| torn_down.set(true))
+                .build()
+                .run(|test| {
+                    temp_dir = Some(test.temp_dir().to_owned());
+                    panic!("Intentional test failure");
+                });
+        }));
+
+        assert!(result.is_err(),
+            "Should re-raise the panic of the body.");
+        assert!(torn_down.get(),
+            "Should run teardown after a panicking body.");
+        let temp_dir = temp_dir.unwrap();
+        assert!(temp_dir.exists(),
+            "Should treat a panicking body as a failure, retaining the temp dir if configured to.");
+        std::fs::remove_dir_all(&temp_dir).unwrap();
+    }
+
+    // Tests configured with `inherit_temp_dir_scoped()` should each have a distinct subdir of the parent's temp dir.
+    // Test configured with `inherit_temp_dir_scoped()` should delete only its own subdir on destruction.
+    #[test] #[named]
+    fn test_temp_dir_inherited_scoped() {
+        let test_a = GROUP_WITH_DIRS.test(function_name!()).inherit_temp_dir_scoped().build();
+        let test_b = GROUP_WITH_DIRS.test(function_name!()).inherit_temp_dir_scoped().build();
+
+        assert_ne!(test_a.temp_dir(), test_b.temp_dir(),
+            "Tests configured with `inherit_temp_dir_scoped()` should each have a distinct subdir of the parent's temp dir.");
+        for test in [&test_a, &test_b] {
+            assert_eq!(GROUP_WITH_DIRS.temp_dir(), test.temp_dir().parent().unwrap());
+            assert!(test.temp_dir().file_name().unwrap().to_str().unwrap().starts_with(function_name!()));
+            assert!(test.temp_dir().exists());
+        }
+
+        let temp_dir_a = test_a.temp_dir().to_owned();
+        drop(test_a);
+        assert!(!temp_dir_a.exists() && test_b.temp_dir().exists() && GROUP_WITH_DIRS.temp_dir().exists(),
+            "Test configured with `inherit_temp_dir_scoped()` should delete only its own subdir on destruction.");
+    }
+
+    // Test should read fixture files as bytes.
+    // Test should error when reading a fixture file that does not exist.
+    #[test] #[named]
+    fn test_fixture_bytes() {
+        let test = MODULE_BASIC.test(function_name!()).using_fixture_dir().build();
+
+        assert_eq!(b"\x00\x01\xfe\xff".to_vec(), test.fixture_bytes("sample.bin").unwrap(),
+            "Test should read fixture files as bytes.");
+        assert!(test.fixture_bytes("missing.bin").is_err(),
+            "Test should error when reading a fixture file that does not exist.");
+    }
+
+    // Should pass for directory trees with the same files and contents.
+    #[test] #[named]
+    fn test_assert_dirs_eq() {
+        let test = MODULE_WITH_DIRS.test(function_name!()).using_fixture_dir().using_temp_dir().build();
+        let expected = test.fixture_dir().join("expected");
+        std::fs::create_dir(test.temp_dir().join("sub")).unwrap();
+        std::fs::write(test.temp_dir().join("a.txt"), "alpha\n").unwrap();
+        std::fs::write(test.temp_dir().join("sub").join("b.txt"), "bravo\n").unwrap();
+
+        testing::assert_dirs_eq(test.temp_dir(), &expected);
+    }
+
+    // Should panic, naming the differing file, for directory trees with different contents.
+    #[test] #[named] #[should_panic(expected = "Directory trees differ at: sub/b.txt (Contents)")]
+    fn test_assert_dirs_eq_differ() {
+        let test = MODULE_WITH_DIRS.test(function_name!()).using_fixture_dir().using_temp_dir().build();
+        let expected = test.fixture_dir().join("expected");
+        std::fs::create_dir(test.temp_dir().join("sub")).unwrap();
+        std::fs::write(test.temp_dir().join("a.txt"), "alpha\n").unwrap();
+        std::fs::write(test.temp_dir().join("sub").join("b.txt"), "charlie\n").unwrap();
+
+        testing::assert_dirs_eq(test.temp_dir(), &expected);
+    }
+
+    // Test should deserialize JSON fixture files.
+    // Test should error with the file path when a JSON fixture file is malformed.
+    #[cfg(feature = "json")]
+    #[test] #[named]
+    fn test_fixture_json() {
+        let test = MODULE_BASIC.test(function_name!()).using_fixture_dir().build();
+
+        let sample: HashMap<String, String> = test.fixture_json("sample.json").unwrap();
+        assert_eq!("Fixture", sample["hello"],
+            "Test should deserialize JSON fixture files.");
+
+        let err = test.fixture_json::<HashMap<String, String>>("malformed.json").unwrap_err();
+        assert!(err.to_string().contains("malformed.json"),
+            "Test should error with the file path when a JSON fixture file is malformed.");
+    }
+
+    // Test should deserialize TOML fixture files.
+    // Test should error with the file path when a TOML fixture file is malformed.
+    #[cfg(feature = "toml")]
+    #[test] #[named]
+    fn test_fixture_toml() {
+        let test = MODULE_BASIC.test(function_name!()).using_fixture_dir().build();
+
+        let sample: HashMap<String, String> = test.fixture_toml("sample.toml").unwrap();
+        assert_eq!("Fixture", sample["hello"],
+            "Test should deserialize TOML fixture files.");
+
+        let err = test.fixture_toml::<HashMap<String, String>>("malformed.toml").unwrap_err();
+        assert!(err.to_string().contains("malformed.toml"),
+            "Test should error with the file path when a TOML fixture file is malformed.");
     }
 
     // Test not configured with a temp dir should panic when attempting to access it 
@@ -504,6 +882,28 @@ mod tests {
  
     }
 
+    // Test configured with `using_fixture_dir_case()` should have a path of: `Test` fixture dir + case
+    #[test] #[named]
+    fn test_fixture_dir_case() {
+        for case in ["alpha", "bravo"] {
+            let test = MODULE_WITH_DIRS.test(function_name!())
+                .using_fixture_dir_case(case)
+                .build();
+
+            assert_eq!(MODULE_WITH_DIRS.fixture_dir().join(function_name!()).join(case), test.fixture_dir(),
+                "Test configured with `using_fixture_dir_case()` should have a path of: `Test` fixture dir + case");
+            assert!(test.fixture_dir().join("input.txt").exists());
+        }
+    }
+
+    // Test configured with `using_fixture_dir_case()` should panic if the case does not exist.
+    #[test] #[should_panic(expected = "Test fixture dir for case `missing` does not exist")]
+    fn test_fixture_dir_case_missing() {
+        MODULE_WITH_DIRS.test("test_fixture_dir_case")
+            .using_fixture_dir_case("missing")  // should panic
+            .build();
+    }
+
     // Test configured to `inherit_fixture_dir()` should have a fixture path that is the same as its Module.
     // Fixture path should exist for Test configured to `inherit_fixture_dir()` from Module
     // Test configured to `inherit_fixture_dir()` should have a fixture path that is the same as its Group.
@@ -529,6 +929,25 @@ mod tests {
             "Fixture path should exist for Test configured to `inherit_fixture_dir()` from Module");
     }
 
+    // Test configured to `inherit_fixture_dir_or_module()` should fall back to its Module's fixture path when its Group has none.
+    // Test configured to `inherit_fixture_dir_or_module()` should prefer its Group's fixture path when configured.
+    #[test] #[named]
+    fn test_fixture_dir_inherited_or_module() {
+        let test = GROUP_BASIC.test(function_name!())
+            .inherit_fixture_dir_or_module()
+            .build();
+
+        assert_eq!(MODULE_WITH_DIRS.fixture_dir(), test.fixture_dir(),
+            "Test configured to `inherit_fixture_dir_or_module()` should fall back to its Module's fixture path when its Group has none.");
+
+        let test = GROUP_WITH_DIRS.test(function_name!())
+            .inherit_fixture_dir_or_module()
+            .build();
+
+        assert_eq!(GROUP_WITH_DIRS.fixture_dir(), test.fixture_dir(),
+            "Test configured to `inherit_fixture_dir_or_module()` should prefer its Group's fixture path when configured.");
+    }
+
     #[test] #[named]
     fn test_import_fixture_dir() {
         let test = MODULE_BASIC.test(function_name!())
@@ -539,6 +958,22 @@ mod tests {
             "Test should import external fixture dir");
     }
 
+    // Test should inherit its Group's imported fixture dirs into its own imports.
+    #[test] #[named]
+    fn test_import_fixture_dir_from_group() {
+        let group = MODULE_BASIC.nonstatic_group(function_name!())
+            .import_fixture_dir(&MODULE_WITH_DIRS.namepath())
+            .build();
+        let test = group.test(function_name!())
+            .build();
+
+        assert_eq!(Some(&MODULE_WITH_DIRS.fixture_dir().to_path_buf()),
+            test.imported_fixture_dirs.as_ref().and_then(|dirs| dirs.get(MODULE_WITH_DIRS.namepath())),
+            "Test should inherit its Group's imported fixture dirs into its own imports.");
+        assert_eq!(MODULE_WITH_DIRS.fixture_dir(), test.imported_fixture_dir(MODULE_WITH_DIRS.namepath()),
+            "Test should inherit its Group's imported fixture dirs into its own imports.");
+    }
+
     #[test] #[named] #[should_panic]
     fn test_import_fixture_dir_fail() {
         let test = MODULE_BASIC.test(function_name!())
@@ -547,6 +982,55 @@ mod tests {
         test.imported_fixture_dir(MODULE_WITH_DIRS.namepath()); // should panic
     }
 
+    // Test should panic when built with a name containing a namepath or path delimiter.
+    #[test] #[should_panic]
+    fn test_name_delimited() {
+        MODULE_BASIC.test("foo.bar").build();
+    }
+
+    // Module configured with test defaults should configure temp and fixture dirs for tests that omit them.
+    // Test configured with an explicit choice should override its Module's defaults.
+    #[test] #[named]
+    fn test_module_defaults() {
+        let module = testing::unit(module_path!())
+            .default_test_temp_dir()
+            .default_test_fixture_dir()
+            .nonstatic()
+            .build();
+
+        let test = module.test(function_name!()).build();
+        assert!(test.temp_dir().exists() && test.temp_dir() != module.temp_dir(),
+            "Module configured with test defaults should configure temp and fixture dirs for tests that omit them.");
+        assert_eq!(MODULE_WITH_DIRS.fixture_dir().join(function_name!()), test.fixture_dir(),
+            "Module configured with test defaults should configure temp and fixture dirs for tests that omit them.");
+
+        let test = module.test(function_name!())
+            .inherit_temp_dir()
+            .build();
+        assert_eq!(module.temp_dir(), test.temp_dir(),
+            "Test configured with an explicit choice should override its Module's defaults.");
+    }
+
+    // Test should import an arbitrary fixture dir by literal path, retrievable by key.
+    #[test] #[named]
+    fn test_import_fixture_path() {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("testing/fixtures/unit");
+        let test = MODULE_BASIC.test(function_name!())
+            .import_fixture_path("shared", &path)
+            .build();
+
+        assert_eq!(path.canonicalize().unwrap(), test.imported_fixture_path("shared"),
+            "Test should import an arbitrary fixture dir by literal path, retrievable by key.");
+    }
+
+    // Test should panic when importing a literal fixture path that does not exist.
+    #[test] #[named] #[should_panic]
+    fn test_import_fixture_path_missing() {
+        MODULE_BASIC.test(function_name!())
+            .import_fixture_path("missing", "testing/fixtures/does_not_exist")
+            .build();
+    }
+
     fn unit_module_namepath() -> Namepath {
         Namepath::module(UseCase::Unit, "asmov_testing::module".to_string())
     }
@@ -567,7 +1051,7 @@ mod tests {
             .import_fixture_dir(&namepath)
             .nonstatic()
             .build();
-        let test_group = test_module.local_group(function_name!()).build();
+        let test_group = test_module.nonstatic_group(function_name!()).build();
         let test = test_group.test(function_name!()).build();
 
         assert_eq!(expected_unit_module_fixture_dir, test.imported_fixture_dir(&namepath),
@@ -581,7 +1065,7 @@ mod tests {
         let test_module = testing::unit(module_path!())
             .nonstatic()
             .build();
-        let test_group = test_module.local_group(function_name!())
+        let test_group = test_module.nonstatic_group(function_name!())
             .import_fixture_dir(&namepath)
             .build();
         let test = test_group.test(function_name!()).build();
@@ -683,5 +1167,25 @@ mod tests {
         assert!(teardown_closure_called,
             "Test teardown closure should be ran on destruction.");
     }
+
+    // Test teardown panics should be caught and logged when the module is configured to catch them.
+    #[test] #[named]
+    fn test_teardown_catch_panics() {
+        let module = testing::unit(module_path!())
+            .teardown_catch_panics()
+            .nonstatic()
+            .build();
+
+        let namepath = {
+            let test = module.test(function_name!())
+                .teardown(|_| panic!("Intentional teardown failure"))
+                .build();
+
+            test.namepath_str().to_owned()
+        };
+
+        assert_eq!(vec![format!("Teardown panicked: {namepath}: Intentional teardown failure")], module.teardown_panics(),
+            "Test teardown panics should be caught and logged when the module is configured to catch them.");
+    }
 }
  
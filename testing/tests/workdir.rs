@@ -0,0 +1,28 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, path::PathBuf};
+    use asmov_testing::{self as testing, prelude::*};
+
+    // Should resolve fixture dirs relative to the crate, regardless of the current working directory.
+    #[test]
+    fn test_fixture_dir_from_subdir() {
+        let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        env::set_current_dir(manifest_dir.join("src")).unwrap();
+
+        let test_module = testing::integration(module_path!())
+            .using_fixture_dir()
+            .nonstatic()
+            .build();
+
+        let expected = manifest_dir
+            .join("testing")
+            .join("fixtures")
+            .join(testing::UseCase::Integration.to_str())
+            .join("workdir")
+            .canonicalize()
+            .unwrap();
+
+        assert_eq!(expected, test_module.fixture_dir(),
+            "Should resolve fixture dirs relative to the crate, regardless of the current working directory.");
+    }
+}
@@ -9,6 +9,8 @@ use crate::str;
 pub struct Cli {
     #[arg(global = true, short, long)]
     pub quiet: bool,
+    #[arg(global = true, long, help = "Disable colored output (Also honors the NO_COLOR environment variable)")]
+    pub no_color: bool,
 
     #[command(subcommand)]
     pub module: CommandModules
@@ -46,6 +48,8 @@ pub enum WorkspaceCommands {
 pub enum TraitCommands {
     Add(AddTraitCommand),
     Remove(RemoveTraitCommand),
+    Sync(SyncTraitCommand),
+    Check(CheckTraitCommand),
 }
 
 #[derive(clap::Args)]
@@ -71,7 +75,7 @@ pub struct NewWorkspaceCommand {
 
 #[derive(clap::Args)]
 pub struct InitWorkspaceCommand {
-    pub library_name: String,
+    pub library_name: Option<String>,
     #[clap(flatten)]
     pub module: WorkspaceCommand,
 }
@@ -100,6 +104,24 @@ pub struct RemoveTraitCommand {
     pub module: TraitCommand
 }
 
+#[derive(clap::Args)]
+#[command(about = "Regenerate the derive integration tests for all traits in a library, preserving custom sections")]
+pub struct SyncTraitCommand {
+    #[arg(long)]
+    pub workspace_path: Option<PathBuf>,
+    #[arg(long)]
+    pub library_name: Option<String>,
+}
+
+#[derive(clap::Args)]
+#[command(about = "Report derive macros without a matching trait in a library, and traits without a derive macro")]
+pub struct CheckTraitCommand {
+    #[arg(long)]
+    pub workspace_path: Option<PathBuf>,
+    #[arg(long)]
+    pub library_name: Option<String>,
+}
+
 fn validate_ident(s: &str) -> Result<String, String> {
     syn::parse_str::<syn::Ident>(s)
         .map(|_| s.to_string())
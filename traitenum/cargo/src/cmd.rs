@@ -1,4 +1,4 @@
-use std::{env, process, path::{PathBuf, Path}};
+use std::{process, path::{PathBuf, Path}};
 use anyhow::Context;
 use crate::{self as lib, str};
 
@@ -9,6 +9,8 @@ pub use workspace::new::new_workspace;
 pub use workspace::init::init_workspace;
 pub use enumtrait::add::add_trait;
 pub use enumtrait::remove::remove_trait;
+pub use enumtrait::sync::sync_traits;
+pub use enumtrait::check::check_traits;
 
 fn quote_error(errmsg: String) -> String {
     let errmsg = errmsg.replace("error: ", "");
@@ -23,6 +25,22 @@ fn quote_error_output(output: process::Output) -> String {
     quote_error(String::from_utf8(output.stderr).unwrap())
 }
 
+/// Infers a library name from the final component of a directory path, sanitized into a valid package name.
+/// E.g., `my-enums/` becomes `my-enums`. Returns None if no sensible name can be derived.
+pub(crate) fn infer_library_name(dir: &Path) -> Option<String> {
+    let dir_name = dir.file_name()?.to_str()?;
+    let name: String = dir_name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' || c == '-' { c.to_ascii_lowercase() } else { '-' })
+        .collect();
+    let name = name.trim_matches(|c| c == '-' || c == '_');
+
+    if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        Some(name.to_owned())
+    } else {
+        None
+    }
+}
+
 fn find_cargo_manifest_file(from_dir: &Path) -> anyhow::Result<PathBuf> {
     let mut current_dir = from_dir.to_owned();
 
@@ -73,8 +91,8 @@ pub(crate) fn find_cargo_workspace_manifest(from_dir: &Path) -> anyhow::Result<(
 }
 
 fn cargo_test(dir: &Path) -> anyhow::Result<()> {
-    env::set_current_dir(dir)?;
     let output = process::Command::new("cargo")
+        .current_dir(dir)
         .arg("test")
         .output()
         .context(lib::Errors::CargoRunError())?;
@@ -86,17 +104,52 @@ fn cargo_test(dir: &Path) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Formats a source file in-place. A non-zero exit (e.g., generated source with a syntax error) is reported along
+/// with rustfmt's stderr.
 pub(crate) fn rustfmt(filepath: &Path) -> anyhow::Result<()> {
     let output = process::Command::new("rustfmt")
         .arg(filepath.to_str().unwrap())
         .output()
-        .context(lib::Errors::RustfmtRunError())?;
+        .map_err(|e| lib::Errors::RustfmtRunError(e.to_string()))?;
 
     if output.status.success() {
        Ok(()) 
     } else {
-        return Err(lib::Errors::RustfmtRunError().into())
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_owned();
+        Err(lib::Errors::RustfmtRunError(stderr).into())
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+    use crate as lib;
+
+    #[test]
+    fn test_infer_library_name() {
+        // Should use the final path component as-is when it is already a valid package name
+        assert_eq!(Some("my-enums"), super::infer_library_name(Path::new("/home/user/my-enums/")).as_deref());
+        // Should sanitize invalid characters and case
+        assert_eq!(Some("my-enums"), super::infer_library_name(Path::new("My Enums")).as_deref());
+        // Should not infer a name that cannot start a package name
+        assert_eq!(None, super::infer_library_name(Path::new("/tmp/42")));
+        assert_eq!(None, super::infer_library_name(Path::new("/")));
+    }
 
+    #[test]
+    fn test_rustfmt_malformed_source() {
+        let src_filepath = std::env::temp_dir()
+            .join(format!("cargo-traitenum-rustfmt-{}.rs", std::process::id()));
+        fs::write(&src_filepath, "pub trait MyTrait { fn name(&self) -> &'static str; ").unwrap();
+
+        // Should fail with rustfmt's stderr rather than passing silently
+        let result = super::rustfmt(&src_filepath);
+        fs::remove_file(&src_filepath).unwrap();
+        let err = result.expect_err("Malformed source should fail to format");
+        match err.downcast_ref::<lib::Errors>() {
+            Some(lib::Errors::RustfmtRunError(stderr)) => assert!(stderr.contains("error"),
+                "Error should carry rustfmt's stderr: {}", stderr),
+            _ => panic!("Unexpected error: {}", err)
+        }
+    }
+}
@@ -1,16 +1,171 @@
-//! Handles all enumtrait commands (add, remove, etc.)
+//! Handles all enumtrait commands (add, remove, sync, check, etc.)
 
-use crate::meta;
+use std::path::Path;
+use anyhow::Context;
+use syn;
+use quote::{self, ToTokens};
+use convert_case::{self as case, Casing};
+use crate::{self as lib, meta, str, cmd};
 
 pub mod add;
 pub mod remove;
+pub mod sync;
+pub mod check;
 
 pub const EXAMPLE_TRAIT_NAME: &'static str = "Example";
 
+/// Finds the library that a command applies to. A workspace with several libraries requires the library name, unless
+/// the directory that the command was run from identifies it.
+fn find_library<'ws>(
+    workspace: &'ws meta::WorkspaceMeta,
+    dir: &Path,
+    library_name: Option<&str>
+) -> anyhow::Result<&'ws meta::LibraryMeta> {
+    if workspace.libraries().len() == 1 {
+        Ok(workspace.libraries().first().unwrap())
+    } else if workspace.libraries().len() > 1 {
+        // without an explicit library name, the directory may still identify the library
+        let library_name = match library_name {
+            Some(name) => name.to_owned(),
+            None => cmd::infer_library_name(dir)
+                .filter(|name| workspace.libraries().iter().any(|lib| lib.name() == name))
+                .context(lib::Errors::AmbiguousLibrary)?
+        };
+
+        workspace.libraries().iter().find(|lib| lib.name() == library_name)
+            .with_context(|| lib::Errors::LibraryNotFound(library_name.to_owned()))
+    } else {
+        anyhow::bail!(lib::Errors::MisconfiguredCargoMetadata(str!("No traitenum libraries found")))
+    }
+}
+
+const GEN_DERIVE_MACRO_NAME: &'static str = "gen_derive_macro";
+const GEN_DERIVE_CRATE_NAME: &'static str = "gen_derive_crate";
+
+/// A derive macro declared by the derive package, either by `gen_derive_macro!()` or within `gen_derive_crate!()`.
+/// Its model bytes const is what ties it to a trait of the lib package.
+#[derive(Debug, PartialEq)]
+struct DeriveMacroMeta {
+    derive_name: String,
+    model_bytes_name: String
+}
+
+/// Whether a top-level item is a call to the named traitenum_lib macro, returning the macro if so.
+fn item_macro<'item>(item: &'item syn::Item, macro_name: &str) -> Option<&'item syn::Macro> {
+    match item {
+        syn::Item::Macro(item_macro) if is_macro(&item_macro.mac, macro_name) => Some(&item_macro.mac),
+        _ => None
+    }
+}
+
+fn is_macro(mac: &syn::Macro, macro_name: &str) -> bool {
+    mac.path.segments.last().is_some_and(|segment| segment.ident == macro_name)
+}
+
+/// The arguments of a `gen_derive_macro!()`, or of each derive macro within a `gen_derive_crate!()`:
+/// (derive name, derive function, model bytes path [, trait path])
+type DeriveMacroArgs = syn::punctuated::Punctuated<syn::Path, syn::Token![,]>;
+
+/// The arguments of a `gen_derive_crate!()`: (lib crate, derive crate, [ (derive macro args), ... ])
+struct DeriveCrateArgs {
+    lib_path: syn::Path,
+    derive_path: syn::Path,
+    derive_macros: Vec<DeriveMacroArgs>
+}
+
+impl DeriveCrateArgs {
+    fn parse(mac: &syn::Macro) -> syn::Result<Self> {
+        mac.parse_body_with(|input: syn::parse::ParseStream| {
+            let lib_path = input.parse()?;
+            input.parse::<syn::Token![,]>()?;
+            let derive_path = input.parse()?;
+            input.parse::<syn::Token![,]>()?;
+            let content;
+            syn::bracketed!(content in input);
+            input.parse::<Option<syn::Token![,]>>()?;
+
+            let derive_macros = content.parse_terminated(|tuple_input| {
+                    let tuple_content;
+                    syn::parenthesized!(tuple_content in tuple_input);
+                    DeriveMacroArgs::parse_terminated(&tuple_content)
+                }, syn::Token![,])?
+                .into_iter()
+                .collect();
+
+            Ok(Self { lib_path, derive_path, derive_macros })
+        })
+    }
+
+    fn to_token_stream(&self) -> proc_macro2::TokenStream {
+        let Self { lib_path, derive_path, derive_macros } = self;
+        quote::quote!{ #lib_path, #derive_path, [ #( (#derive_macros), )* ] }
+    }
+}
+
+fn derive_macro_meta(args: &DeriveMacroArgs, mac: &syn::Macro) -> syn::Result<DeriveMacroMeta> {
+    let ident = |index: usize| args.iter().nth(index)
+        .and_then(|path| path.segments.last())
+        .map(|segment| segment.ident.to_token_stream().to_string())
+        .ok_or_else(|| syn::Error::new_spanned(mac, "Missing derive macro argument"));
+
+    Ok(DeriveMacroMeta {
+        derive_name: ident(0)?,
+        model_bytes_name: ident(2)?
+    })
+}
+
+/// Lists the derive macros of a derive package's `src/lib.rs`, in order.
+fn parse_derive_macros(derive_src_file: &syn::File) -> syn::Result<Vec<DeriveMacroMeta>> {
+    let mut derive_macros = Vec::new();
+    for item in &derive_src_file.items {
+        if let Some(mac) = item_macro(item, GEN_DERIVE_MACRO_NAME) {
+            derive_macros.push(derive_macro_meta(&mac.parse_body_with(DeriveMacroArgs::parse_terminated)?, mac)?);
+        } else if let Some(mac) = item_macro(item, GEN_DERIVE_CRATE_NAME) {
+            for args in &DeriveCrateArgs::parse(mac)?.derive_macros {
+                derive_macros.push(derive_macro_meta(args, mac)?);
+            }
+        }
+    }
+
+    Ok(derive_macros)
+}
+
+/// The model bytes const that the lib package exports for a trait. E.g., `TRAITENUM_MODEL_BYTES_MY_TRAIT`
+fn model_bytes_name(trait_name: &str) -> String {
+    format!("{}{}", add::DERIVE_MODEL_BYTES_PREFIX, trait_name.to_case(case::Case::ScreamingSnake))
+}
+
 fn has_enumtrait(
     trait_name: &str,
     workspace: &meta::WorkspaceMeta,
     library: &meta::LibraryMeta
 ) -> anyhow::Result<bool> {
     todo!()
-}
\ No newline at end of file
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    /// Scaffolds a workspace with a single, empty library named `my-lib` in the temp dir. Callers remove it.
+    pub(super) fn scaffold_workspace(name: &str) -> std::path::PathBuf {
+        let workspace_path = std::env::temp_dir()
+            .join(format!("cargo-traitenum-{}-{}", name, std::process::id()));
+
+        fs::create_dir_all(workspace_path.join("lib").join("src")).unwrap();
+        fs::create_dir_all(workspace_path.join("derive").join("src")).unwrap();
+        fs::create_dir_all(workspace_path.join("derive").join("tests")).unwrap();
+        fs::write(workspace_path.join("Cargo.toml"), concat!(
+            "[workspace]\nmembers = [\"lib\", \"derive\"]\n\n",
+            "[[workspace.metadata.traitenum.library]]\nname = \"my-lib\"\nlib-dir = \"lib\"\nderive-dir = \"derive\"\n"))
+            .unwrap();
+        fs::write(workspace_path.join("lib").join("Cargo.toml"), "[package]\nname = \"my-lib\"\n").unwrap();
+        fs::write(workspace_path.join("lib").join("src").join("lib.rs"), "").unwrap();
+        fs::write(workspace_path.join("derive").join("Cargo.toml"),
+            "[package]\nname = \"my-lib-derive\"\n\n[dependencies]\nmy-lib = \"0.1.0\"\n").unwrap();
+        fs::write(workspace_path.join("derive").join("src").join("lib.rs"),
+            "traitenum_lib::gen_require!(my_lib, my_lib_derive);\n").unwrap();
+        workspace_path
+    }
+}
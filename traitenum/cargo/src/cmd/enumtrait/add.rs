@@ -1,4 +1,4 @@
-use std::{fs, process, env};
+use std::{fs, env, path::{Path, PathBuf}};
 use anyhow::Context;
 use syn;
 use quote::{self, ToTokens};
@@ -16,20 +16,10 @@ pub fn add_trait(args: cli::AddTraitCommand, quiet: bool, test: bool) -> anyhow:
 
     let workspace = meta::build(&dir)?;
 
-    // find the library
-    let library = if workspace.libraries().len() == 1 {
-        workspace.libraries().first().unwrap()
-    } else if workspace.libraries().len() > 1 {
-        let library_name = match &args.module.library_name {
-            Some(name) => name,
-            None => anyhow::bail!(lib::Errors::AmbiguousLibrary)
-        };
-
-        workspace.libraries().iter().find(|lib| lib.name() == library_name)
-            .context(lib::Errors::LibraryNotFound(library_name.to_owned()))?
-    } else {
-        anyhow::bail!(lib::Errors::MisconfiguredCargoMetadata(str!("No traitenum libraries found")))
-    };
+    let library = super::find_library(&workspace, &dir, args.module.library_name.as_deref())?;
+
+    // verify before writing anything, so that a mismatched pair doesn't leave the workspace half-modified
+    verify_derive_lib(&workspace.derive_path(library), library.lib_name())?;
 
     if library.traits().iter().find(|t| t.name() == args.module.trait_name).is_some() {
         anyhow::bail!(lib::Errors::DuplicateTrait(args.module.trait_name, library.name().to_owned()))
@@ -76,12 +66,8 @@ fn add_lib_trait(
     let mut lib_src_file = syn::parse_file(&lib_src).unwrap();
     lib_src_file.items.push(trait_item);
     fs::write(&lib_src_path, lib_src_file.to_token_stream().to_string())?;
+    cmd::rustfmt(&lib_src_path)?;
 
-    process::Command::new("rustfmt")
-        .arg(lib_src_path.to_str().unwrap())
-        .output()
-        .expect("Unable to run: rustfmt");
- 
     Ok(())
 }
 
@@ -92,10 +78,25 @@ fn add_derive_macro(
 ) -> anyhow::Result<()> {
     let derive_src_path = workspace.derive_path(library).join("src").join("lib.rs");
     let derive_src = std::fs::read_to_string(&derive_src_path).unwrap();
-    let trait_item = derive_item(trait_ident);
-
     let mut derive_src_file = syn::parse_file(&derive_src).unwrap();
-    derive_src_file.items.push(trait_item);
+
+    // a derive crate generated by `gen_derive_crate!()` lists each of its derive macros within that call
+    let derive_crate_mac = derive_src_file.items.iter_mut()
+        .find_map(|item| match item {
+            syn::Item::Macro(item_macro) if super::is_macro(&item_macro.mac, super::GEN_DERIVE_CRATE_NAME) =>
+                Some(&mut item_macro.mac),
+            _ => None
+        });
+
+    if let Some(mac) = derive_crate_mac {
+        let mut derive_crate_args = super::DeriveCrateArgs::parse(mac)
+            .with_context(|| lib::Errors::SourceParsing(str!("Invalid `gen_derive_crate!()`"), derive_src_path.to_owned()))?;
+        let model_bytes_ident = model_bytes_ident(trait_ident);
+        derive_crate_args.derive_macros.push(derive_macro_args(trait_ident, syn::parse_quote!(#model_bytes_ident)));
+        mac.tokens = derive_crate_args.to_token_stream();
+    } else {
+        derive_src_file.items.push(derive_item(trait_ident));
+    }
 
     fs::write(&derive_src_path, derive_src_file.to_token_stream().to_string())?;
     cmd::rustfmt(&derive_src_path)?;
@@ -103,6 +104,50 @@ fn add_derive_macro(
     Ok(())
 }
 
+const GEN_REQUIRE_MACRO_NAME: &'static str = "gen_require";
+
+/// Ensures that the derive package depends upon the lib package and that its `gen_require!()` (or
+/// `gen_derive_crate!()`) refers to the same lib crate. A mismatched pair otherwise only surfaces as confusing errors
+/// once a derive macro is used.
+fn verify_derive_lib(derive_path: &Path, lib_name: &str) -> anyhow::Result<()> {
+    let manifest_filepath = derive_path.join("Cargo.toml");
+    let manifest = cmd::read_manifest(&manifest_filepath)?;
+    let has_lib_dependency = manifest.get("dependencies")
+        .and_then(|dependencies| dependencies.as_table())
+        .is_some_and(|dependencies| dependencies.contains_key(lib_name));
+
+    if !has_lib_dependency {
+        anyhow::bail!(lib::Errors::MissingLibDependency(lib_name.to_owned(), manifest_filepath))
+    }
+
+    let derive_src_path = derive_path.join("src").join("lib.rs");
+    let derive_src_file = syn::parse_file(&fs::read_to_string(&derive_src_path)?)
+        .with_context(|| lib::Errors::SourceParsing(str!("Invalid syntax"), derive_src_path.to_owned()))?;
+
+    let required_lib_path = derive_src_file.items.iter()
+        .find_map(|item| if let Some(mac) = super::item_macro(item, GEN_REQUIRE_MACRO_NAME) {
+                Some(mac.parse_body_with(super::DeriveMacroArgs::parse_terminated).ok()
+                    .and_then(|paths| paths.first().cloned()))
+            } else if let Some(mac) = super::item_macro(item, super::GEN_DERIVE_CRATE_NAME) {
+                Some(super::DeriveCrateArgs::parse(mac).ok().map(|args| args.lib_path))
+            } else {
+                None
+            })
+        .with_context(|| lib::Errors::SourceParsing(
+            str!("Missing `gen_require!()` or `gen_derive_crate!()`"), derive_src_path.to_owned()))?
+        .with_context(|| lib::Errors::SourceParsing(
+            str!("Invalid `gen_require!()` or `gen_derive_crate!()`"), derive_src_path.to_owned()))?;
+
+    let required_lib_crate_name = required_lib_path.to_token_stream().to_string();
+
+    let lib_crate_name = lib_name.to_case(case::Case::Snake);
+    if required_lib_crate_name != lib_crate_name {
+        anyhow::bail!(lib::Errors::MismatchedGenRequire(lib_crate_name, required_lib_crate_name, derive_src_path))
+    }
+
+    Ok(())
+}
+
 fn remove_example_trait(
     _trait_ident: &syn::Ident,
     _workspace: &meta::WorkspaceMeta,
@@ -137,20 +182,26 @@ fn build_derive_test(
     workspace: &meta::WorkspaceMeta,
     library: &LibraryMeta
 ) -> anyhow::Result<()> {
-    let trait_snake_name = trait_name.to_case(case::Case::Snake);
+    let test_src = render_derive_test(trait_name, library);
+    let test_src_path = derive_test_path(trait_name, workspace, library);
+    fs::write(test_src_path, test_src)?;
 
-    let test_src = DERIVE_TEST_TEMPLATE
+    Ok(())
+}
+
+/// Renders the derive package's integration test for a trait from [DERIVE_TEST_TEMPLATE].
+pub(super) fn render_derive_test(trait_name: &str, library: &LibraryMeta) -> String {
+    DERIVE_TEST_TEMPLATE
         .replace(VAR_DERIVE_CRATE_NAME, &library.derive_name().to_case(case::Case::Snake))
         .replace(VAR_LIB_CRATE_NAME, &library.lib_name().to_case(case::Case::Snake))
         .replace(VAR_TRAIT_NAME, &trait_name)
-        .replace(VAR_TRAIT_SNAKE_NAME, &trait_snake_name);
-
-    let test_src_path = workspace.derive_path(library).join("tests")
-        .join(format!("{}{}", trait_snake_name, ".rs"));
-
-    fs::write(test_src_path, test_src)?;
+        .replace(VAR_TRAIT_SNAKE_NAME, &trait_name.to_case(case::Case::Snake))
+}
 
-    Ok(())
+/// path: { derive dir } / tests / { trait snake name }.rs
+pub(super) fn derive_test_path(trait_name: &str, workspace: &meta::WorkspaceMeta, library: &LibraryMeta) -> PathBuf {
+    workspace.derive_path(library).join("tests")
+        .join(format!("{}{}", trait_name.to_case(case::Case::Snake), ".rs"))
 }
 
 fn test_workspace(workspace: meta::WorkspaceMeta) -> anyhow::Result<()> {
@@ -172,26 +223,28 @@ fn trait_item(trait_ident: &syn::Ident) -> syn::Item {
 }
 
 const DERIVE_MACRO_FN_PREFIX: &'static str = "derive_traitenum_";
-const DERIVE_MODEL_BYTES_PREFIX: &'static str = "TRAITENUM_MODEL_BYTES_";
+pub(super) const DERIVE_MODEL_BYTES_PREFIX: &'static str = "TRAITENUM_MODEL_BYTES_";
+
+fn model_bytes_ident(trait_ident: &syn::Ident) -> syn::Ident {
+    syn::Ident::new(&super::model_bytes_name(&trait_ident.to_string()), proc_macro2::Span::call_site())
+}
 
-fn derive_item(derive_ident: &syn::Ident) -> syn::Item {
-    let trait_name = derive_ident.to_string();
+/// (derive name, derive function, model bytes path). The derive is named after its trait.
+fn derive_macro_args(trait_ident: &syn::Ident, model_bytes_path: syn::Path) -> super::DeriveMacroArgs {
     let derive_macro_fn_ident = syn::Ident::new(
-        &format!("{}{}", DERIVE_MACRO_FN_PREFIX, trait_name.to_case(case::Case::Snake)),
-        proc_macro2::Span::call_site());
-    let derive_model_const_ident = syn::Ident::new(
-        &format!("{}{}", DERIVE_MODEL_BYTES_PREFIX, trait_name.to_case(case::Case::ScreamingSnake)),
+        &format!("{}{}", DERIVE_MACRO_FN_PREFIX, trait_ident.to_string().to_case(case::Case::Snake)),
         proc_macro2::Span::call_site());
 
-    let item = quote::quote!{
-        traitenum_lib::gen_derive_macro!(
-            #derive_ident,
-            #derive_macro_fn_ident,
-            traitlib::#derive_model_const_ident
-        );
-    };
+    syn::parse_quote!(#trait_ident, #derive_macro_fn_ident, #model_bytes_path)
+}
 
-    syn::parse2(item).unwrap()
+fn derive_item(trait_ident: &syn::Ident) -> syn::Item {
+    let model_bytes_ident = model_bytes_ident(trait_ident);
+    let derive_macro_args = derive_macro_args(trait_ident, syn::parse_quote!(traitlib::#model_bytes_ident));
+
+    syn::parse_quote!{
+        traitenum_lib::gen_derive_macro!(#derive_macro_args);
+    }
 }
 
 const VAR_LIB_CRATE_NAME: &'static str = "%{LIB_CRATE_NAME}%";
@@ -199,6 +252,10 @@ const VAR_DERIVE_CRATE_NAME: &'static str = "%{DERIVE_CRATE_NAME}%";
 const VAR_TRAIT_NAME: &'static str = "%{TRAIT_NAME}%";
 const VAR_TRAIT_SNAKE_NAME: &'static str = "%{TRAIT_SNAKE_NAME}%";
 
+/// Guards a region of a generated derive test that is preserved as-is by `trait sync`.
+pub(super) const CUSTOM_SECTION_BEGIN: &'static str = "// traitenum:custom:begin";
+pub(super) const CUSTOM_SECTION_END: &'static str = "// traitenum:custom:end";
+
 const DERIVE_TEST_TEMPLATE: &'static str =
 r#"
 #[cfg(test)]
@@ -222,5 +279,88 @@ mod tests {
         assert_eq!(1, MyEnum::Bravo.ordinal());
         assert_eq!(2, MyEnum::Charlie.ordinal());
     }
+
+    // traitenum:custom:begin
+    // traitenum:custom:end
 }
 "#;
+
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::{self as lib, cli, str};
+    use super::super::tests::scaffold_workspace;
+
+    fn scaffold_derive(name: &str, manifest: &str, src: &str) -> std::path::PathBuf {
+        let derive_path = std::env::temp_dir()
+            .join(format!("cargo-traitenum-{}-{}", name, std::process::id()));
+
+        fs::create_dir_all(derive_path.join("src")).unwrap();
+        fs::write(derive_path.join("Cargo.toml"), manifest).unwrap();
+        fs::write(derive_path.join("src").join("lib.rs"), src).unwrap();
+        derive_path
+    }
+
+    #[test]
+    fn test_verify_derive_lib() {
+        let manifest = "[package]\nname = \"my-lib-derive\"\n\n[dependencies]\nmy-lib = \"0.1.0\"\n";
+
+        // Should accept a derive package that requires its own lib crate
+        let derive_path = scaffold_derive("matched", manifest,
+            "traitenum_lib::gen_require!(my_lib, my_lib_derive);\n");
+        let result = super::verify_derive_lib(&derive_path, "my-lib");
+        fs::remove_dir_all(&derive_path).unwrap();
+        assert!(result.is_ok(), "Matching lib and derive crates should verify: {:?}", result);
+
+        // Should accept a derive package generated by gen_derive_crate!()
+        let derive_path = scaffold_derive("crate", manifest,
+            "traitenum_lib::gen_derive_crate!(my_lib, my_lib_derive, []);\n");
+        let result = super::verify_derive_lib(&derive_path, "my-lib");
+        fs::remove_dir_all(&derive_path).unwrap();
+        assert!(result.is_ok(), "A gen_derive_crate!() derive package should verify: {:?}", result);
+
+        // Should report a derive package whose gen_require!() refers to a different lib crate
+        let derive_path = scaffold_derive("mismatched", manifest,
+            "traitenum_lib::gen_require!(other_lib, my_lib_derive);\n");
+        let result = super::verify_derive_lib(&derive_path, "my-lib");
+        fs::remove_dir_all(&derive_path).unwrap();
+        let err = result.expect_err("Mismatched gen_require!() should fail verification");
+        assert!(matches!(err.downcast_ref::<lib::Errors>(),
+                Some(lib::Errors::MismatchedGenRequire(expected, found, _)) if expected == "my_lib" && found == "other_lib"),
+            "Unexpected error: {}", err);
+        assert!(err.to_string().contains("refers to `other_lib` instead of lib crate `my_lib`"),
+            "Diagnostic should name both crates: {}", err);
+
+        // Should report a derive package that does not depend upon the lib package
+        let derive_path = scaffold_derive("undeclared", "[package]\nname = \"my-lib-derive\"\n",
+            "traitenum_lib::gen_require!(my_lib, my_lib_derive);\n");
+        let result = super::verify_derive_lib(&derive_path, "my-lib");
+        fs::remove_dir_all(&derive_path).unwrap();
+        let err = result.expect_err("Missing lib dependency should fail verification");
+        assert!(matches!(err.downcast_ref::<lib::Errors>(), Some(lib::Errors::MissingLibDependency(..))),
+            "Unexpected error: {}", err);
+
+        // Should fail to add a trait to a mismatched pair, before modifying the lib package
+        let workspace_path = scaffold_workspace("add-mismatched");
+        let lib_path = workspace_path.join("lib");
+        fs::write(workspace_path.join("derive").join("src").join("lib.rs"),
+            "traitenum_lib::gen_require!(other_lib, my_lib_derive);\n").unwrap();
+        let before = (fs::read_to_string(lib_path.join("src").join("lib.rs")).unwrap(),
+            fs::read_to_string(lib_path.join("Cargo.toml")).unwrap());
+
+        let result = crate::cmd::add_trait(cli::AddTraitCommand { module: cli::TraitCommand {
+                trait_name: str!("MyTrait"),
+                workspace_path: Some(workspace_path.to_owned()),
+                library_name: None
+            }}, true, false);
+        let after = (fs::read_to_string(lib_path.join("src").join("lib.rs")).unwrap(),
+            fs::read_to_string(lib_path.join("Cargo.toml")).unwrap());
+        fs::remove_dir_all(&workspace_path).unwrap();
+
+        let err = result.expect_err("Adding a trait to a mismatched pair should fail");
+        assert!(matches!(err.downcast_ref::<lib::Errors>(), Some(lib::Errors::MismatchedGenRequire(..))),
+            "Unexpected error: {}", err);
+        assert_eq!(before, after, "A failed `add` should leave the lib package untouched");
+    }
+}
@@ -0,0 +1,125 @@
+use std::{fs, env};
+use anyhow::Context;
+use syn;
+use crate::{self as lib, cli, meta, str};
+
+pub fn check_traits(args: cli::CheckTraitCommand, quiet: bool) -> anyhow::Result<()> {
+    let dir = if let Some(ref workspace_path) = args.workspace_path {
+        workspace_path.to_owned()
+    } else {
+        env::current_dir()?
+    };
+
+    let workspace = meta::build(&dir)?;
+    let library = super::find_library(&workspace, &dir, args.library_name.as_deref())?;
+
+    lib::log(quiet, "Checking derive macros against lib traits ...");
+    let derive_macros = derive_macros(&workspace, library)?;
+
+    // each derive macro is tied to its lib trait by the model bytes const that it's generated from
+    let mut errors: Vec<lib::Errors> = derive_macros.iter()
+        .filter(|derive_macro| !library.traits().iter()
+            .any(|t| super::model_bytes_name(t.name()) == derive_macro.model_bytes_name))
+        .map(|derive_macro| lib::Errors::OrphanedDeriveMacro(
+            derive_macro.derive_name.to_owned(), library.name().to_owned()))
+        .collect();
+
+    errors.extend(library.traits().iter()
+        .filter(|t| !derive_macros.iter()
+            .any(|derive_macro| derive_macro.model_bytes_name == super::model_bytes_name(t.name())))
+        .map(|t| lib::Errors::MissingDeriveMacro(t.name().to_owned(), library.name().to_owned())));
+
+    for error in &errors {
+        lib::log_error(&error.to_string());
+    }
+
+    if let Some(error) = errors.into_iter().next() {
+        return Err(error.into());
+    }
+
+    lib::log_success(quiet, "Your enumtraits and derive macros are consistent.");
+
+    Ok(())
+}
+
+/// Lists the derive macros of the derive package, in order.
+fn derive_macros(
+    workspace: &meta::WorkspaceMeta,
+    library: &meta::LibraryMeta
+) -> anyhow::Result<Vec<super::DeriveMacroMeta>> {
+    let derive_src_path = workspace.derive_path(library).join("src").join("lib.rs");
+    let derive_src_file = syn::parse_file(&fs::read_to_string(&derive_src_path)?)
+        .with_context(|| lib::Errors::SourceParsing(str!("Invalid syntax"), derive_src_path.to_owned()))?;
+
+    super::parse_derive_macros(&derive_src_file)
+        .with_context(|| lib::Errors::SourceParsing(str!("Invalid derive macro"), derive_src_path.to_owned()))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::{self as lib, cli, str};
+    use super::super::tests::scaffold_workspace;
+
+    #[test]
+    fn test_check_traits() {
+        let workspace_path = scaffold_workspace("check");
+        let derive_src_path = workspace_path.join("derive").join("src").join("lib.rs");
+        let check = || crate::cmd::check_traits(cli::CheckTraitCommand {
+                workspace_path: Some(workspace_path.to_owned()),
+                library_name: None
+            }, true);
+
+        let result = crate::cmd::add_trait(cli::AddTraitCommand { module: cli::TraitCommand {
+                trait_name: str!("MyTrait"),
+                workspace_path: Some(workspace_path.to_owned()),
+                library_name: None
+            }}, true, false)
+            .and_then(|_| {
+                let consistent_result = check();
+
+                // a derive macro lingers after its trait was removed from the lib crate
+                let mut derive_src = fs::read_to_string(&derive_src_path)?;
+                derive_src.push_str(
+                    "traitenum_lib::gen_derive_macro!(StaleTraitEnum, derive_traitenum_stale_trait, traitlib::TRAITENUM_MODEL_BYTES_STALE_TRAIT);\n");
+                fs::write(&derive_src_path, derive_src)?;
+                let orphaned_result = check();
+
+                // a single gen_derive_crate!() whose derive name differs from its trait's name
+                fs::write(&derive_src_path, concat!(
+                    "traitenum_lib::gen_derive_crate!(my_lib, my_lib_derive, [\n",
+                    "    (MyTraitEnum, derive_traitenum_my_trait, TRAITENUM_MODEL_BYTES_MY_TRAIT),\n",
+                    "]);\n"))?;
+                let derive_crate_result = check();
+
+                // a gen_derive_crate!() that omits the trait
+                fs::write(&derive_src_path, "traitenum_lib::gen_derive_crate!(my_lib, my_lib_derive, [\n]);\n")?;
+
+                Ok((consistent_result, orphaned_result, derive_crate_result, check()))
+            });
+
+        fs::remove_dir_all(&workspace_path).unwrap();
+        let (consistent_result, orphaned_result, derive_crate_result, missing_result) = result.unwrap();
+
+        // Should pass while each trait has a derive macro
+        assert!(consistent_result.is_ok(), "A consistent workspace should pass the check: {:?}", consistent_result);
+
+        // Should report the orphaned derive macro by its derive name
+        let err = orphaned_result.expect_err("An orphaned derive macro should fail the check");
+        assert!(matches!(err.downcast_ref::<lib::Errors>(),
+                Some(lib::Errors::OrphanedDeriveMacro(derive_name, library)) if derive_name == "StaleTraitEnum" && library == "my-lib"),
+            "Unexpected error: {}", err);
+        assert!(err.to_string().contains("StaleTraitEnum"), "Diagnostic should name the derive macro: {}", err);
+
+        // Should match the derive macros of a gen_derive_crate!() to traits by their model bytes
+        assert!(derive_crate_result.is_ok(), "A consistent gen_derive_crate!() should pass the check: {:?}",
+            derive_crate_result);
+
+        // Should report a trait that is missing from a gen_derive_crate!()
+        let err = missing_result.expect_err("A missing derive macro should fail the check");
+        assert!(matches!(err.downcast_ref::<lib::Errors>(),
+                Some(lib::Errors::MissingDeriveMacro(trait_name, _)) if trait_name == "MyTrait"),
+            "Unexpected error: {}", err);
+    }
+}
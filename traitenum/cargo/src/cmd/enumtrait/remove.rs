@@ -1,5 +1,4 @@
 use std::{fs, env};
-use anyhow::Context;
 use syn;
 use quote::{self, ToTokens};
 use crate::{self as lib, cli, meta, str, cmd};
@@ -14,20 +13,7 @@ pub fn remove_trait(args: cli::RemoveTraitCommand, quiet: bool) -> anyhow::Resul
 
     let workspace = meta::build(&dir)?;
 
-    // find the library
-    let library = if workspace.libraries().len() == 1 {
-        workspace.libraries().first().unwrap()
-    } else if workspace.libraries().len() > 1 {
-        let library_name = match &args.module.library_name {
-            Some(name) => name,
-            None => anyhow::bail!(lib::Errors::AmbiguousLibrary)
-        };
-
-        workspace.libraries().iter().find(|lib| lib.name() == library_name)
-            .context(lib::Errors::LibraryNotFound(library_name.to_owned()))?
-    } else {
-        anyhow::bail!(lib::Errors::MisconfiguredCargoMetadata(str!("No traitenum libraries found")))
-    };
+    let library = super::find_library(&workspace, &dir, args.module.library_name.as_deref())?;
 
     // find the trait
     let trait_meta = match library.traits().iter().find(|t| t.name() == args.module.trait_name) {
@@ -37,10 +23,10 @@ pub fn remove_trait(args: cli::RemoveTraitCommand, quiet: bool) -> anyhow::Resul
 
     lib::log(quiet, "Removing trait from lib package ...");
     rm_lib_trait(trait_meta, library, &workspace)?;
+    lib::log(quiet, "Removing macro from derive package ...");
+    rm_derive_macro(trait_meta, library, &workspace)?;
     /*lib::log("Removing trait from lib manifest ...");
     update_lib_manifest()?;
-    lib::log("Removing macro from derive package ...");
-    rm_derive_macro()?;
     lib::log("Removing integration test from derive package ...");
     rm_derive_integration_test()?;
     lib::log("Testing workspace ...");
@@ -99,4 +85,111 @@ fn rm_lib_trait(
     cmd::rustfmt(&src_filepath)?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Removes the trait's derive macro, whether it's a `gen_derive_macro!()` or listed within `gen_derive_crate!()`.
+/// Derive macros are matched by the trait's model bytes const.
+fn rm_derive_macro(
+    trait_meta: &meta::TraitMeta,
+    library: &meta::LibraryMeta,
+    workspace: &meta::WorkspaceMeta
+) -> anyhow::Result<()> {
+    let model_bytes_name = super::model_bytes_name(trait_meta.name());
+    let is_trait_derive_macro = |args: &super::DeriveMacroArgs, mac: &syn::Macro| super::derive_macro_meta(args, mac)
+        .is_ok_and(|derive_macro| derive_macro.model_bytes_name == model_bytes_name);
+
+    let src_filepath = workspace.derive_path(library).join("src").join("lib.rs");
+    let mut src = syn::parse_file(&fs::read_to_string(&src_filepath)?)?;
+
+    let mut found = false;
+    src.items.retain_mut(|item| {
+        let mac = match item {
+            syn::Item::Macro(item_macro) => &mut item_macro.mac,
+            _ => return true
+        };
+
+        if super::is_macro(mac, super::GEN_DERIVE_MACRO_NAME) {
+            let matched = mac.parse_body_with(super::DeriveMacroArgs::parse_terminated)
+                .is_ok_and(|args| is_trait_derive_macro(&args, mac));
+            found |= matched;
+            !matched
+        } else if super::is_macro(mac, super::GEN_DERIVE_CRATE_NAME) {
+            if let Ok(mut derive_crate_args) = super::DeriveCrateArgs::parse(mac) {
+                let len = derive_crate_args.derive_macros.len();
+                derive_crate_args.derive_macros.retain(|args| !is_trait_derive_macro(args, mac));
+                if derive_crate_args.derive_macros.len() < len {
+                    found = true;
+                    mac.tokens = derive_crate_args.to_token_stream();
+                }
+            }
+
+            true
+        } else {
+            true
+        }
+    });
+
+    if !found {
+        lib::log_warn(&format!("Derive macro for trait `{}` not found. Skipped", trait_meta.name()));
+        return Ok(());
+    }
+
+    fs::write(&src_filepath, src.to_token_stream().to_string())?;
+    cmd::rustfmt(&src_filepath)?;
+
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::Path};
+    use crate::{cli, str};
+    use super::super::tests::scaffold_workspace;
+
+    fn trait_command(trait_name: &str, workspace_path: &Path) -> cli::TraitCommand {
+        cli::TraitCommand {
+            trait_name: trait_name.to_owned(),
+            workspace_path: Some(workspace_path.to_owned()),
+            library_name: None
+        }
+    }
+
+    // Adds two traits, removes one, and returns the resulting derive source with whitespace stripped
+    fn add_and_remove(workspace_path: &Path) -> anyhow::Result<String> {
+        crate::cmd::add_trait(cli::AddTraitCommand { module: trait_command("MyTrait", workspace_path) }, true, false)?;
+        crate::cmd::add_trait(cli::AddTraitCommand { module: trait_command("OtherTrait", workspace_path) }, true, false)?;
+        crate::cmd::remove_trait(cli::RemoveTraitCommand { module: trait_command("MyTrait", workspace_path) }, true)?;
+
+        let lib_src = fs::read_to_string(workspace_path.join("lib").join("src").join("lib.rs"))?;
+        anyhow::ensure!(!lib_src.contains("trait MyTrait") && lib_src.contains("trait OtherTrait"),
+            "Only MyTrait should be removed from the lib package: {}", lib_src);
+
+        Ok(fs::read_to_string(workspace_path.join("derive").join("src").join("lib.rs"))?
+            .split_whitespace()
+            .collect())
+    }
+
+    #[test]
+    fn test_remove_trait() {
+        // Should drop the trait's gen_derive_macro!() call
+        let workspace_path = scaffold_workspace("remove-macro");
+        let result = add_and_remove(&workspace_path);
+        fs::remove_dir_all(&workspace_path).unwrap();
+        let derive_src = result.unwrap();
+        assert!(!derive_src.contains("TRAITENUM_MODEL_BYTES_MY_TRAIT)")
+                && derive_src.contains("derive_traitenum_other_trait"),
+            "Only the MyTrait derive macro should be removed: {}", derive_src);
+
+        // Should drop the trait's entry from gen_derive_crate!()
+        let workspace_path = scaffold_workspace("remove-crate");
+        fs::write(workspace_path.join("derive").join("src").join("lib.rs"),
+            "traitenum_lib::gen_derive_crate!(my_lib, my_lib_derive, []);\n").unwrap();
+        let result = add_and_remove(&workspace_path);
+        fs::remove_dir_all(&workspace_path).unwrap();
+        let derive_src = result.unwrap();
+        assert_eq!(str!("traitenum_lib::gen_derive_crate!(my_lib,my_lib_derive,\
+                [(OtherTrait,derive_traitenum_other_trait,TRAITENUM_MODEL_BYTES_OTHER_TRAIT),]);"),
+            derive_src);
+    }
+}
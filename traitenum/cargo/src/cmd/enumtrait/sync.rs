@@ -0,0 +1,119 @@
+use std::{fs, env, path::Path};
+use anyhow::Context;
+use crate::{self as lib, cli, meta};
+
+use super::add::{self, CUSTOM_SECTION_BEGIN, CUSTOM_SECTION_END};
+
+pub fn sync_traits(args: cli::SyncTraitCommand, quiet: bool) -> anyhow::Result<()> {
+    let dir = if let Some(ref workspace_path) = args.workspace_path {
+        workspace_path.to_owned()
+    } else {
+        env::current_dir()?
+    };
+
+    let workspace = meta::build(&dir)?;
+
+    let library = super::find_library(&workspace, &dir, args.library_name.as_deref())?;
+
+    for trait_meta in library.traits() {
+        lib::log(quiet, &format!("Regenerating integration test for `{}` ...", trait_meta.name()));
+        sync_derive_test(trait_meta, &workspace, library)?;
+    }
+
+    lib::log_success(quiet, "Your enumtrait tests are in sync.");
+
+    Ok(())
+}
+
+fn sync_derive_test(
+    trait_meta: &meta::TraitMeta,
+    workspace: &meta::WorkspaceMeta,
+    library: &meta::LibraryMeta
+) -> anyhow::Result<()> {
+    let test_src_path = add::derive_test_path(trait_meta.name(), workspace, library);
+    let test_src = add::render_derive_test(trait_meta.name(), library);
+
+    let test_src = if test_src_path.exists() {
+        let existing_src = fs::read_to_string(&test_src_path)?;
+        match custom_section(&existing_src, &test_src_path)? {
+            Some(custom) => replace_custom_section(&test_src, custom),
+            None => {
+                // without guards, there is no telling which parts were hand-edited
+                lib::log_warn(&format!(
+                    "Integration test has no custom section guards. Skipped: {}",
+                    test_src_path.to_str().unwrap()));
+
+                return Ok(());
+            }
+        }
+    } else {
+        test_src
+    };
+
+    fs::write(&test_src_path, test_src)?;
+
+    Ok(())
+}
+
+/// Returns the text between the custom section guards, exclusive, if the guards are present.
+fn custom_section<'src>(src: &'src str, src_path: &Path) -> anyhow::Result<Option<&'src str>> {
+    let begin = match src.find(CUSTOM_SECTION_BEGIN) {
+        Some(offset) => offset + CUSTOM_SECTION_BEGIN.len(),
+        None => return Ok(None)
+    };
+
+    let end = src[begin..].find(CUSTOM_SECTION_END)
+        .with_context(|| lib::Errors::UnterminatedCustomSection(CUSTOM_SECTION_END.to_owned(), src_path.to_owned()))?;
+
+    Ok(Some(&src[begin .. begin + end]))
+}
+
+fn replace_custom_section(src: &str, custom: &str) -> String {
+    let begin = src.find(CUSTOM_SECTION_BEGIN).expect("Template should have a custom section") + CUSTOM_SECTION_BEGIN.len();
+    let end = begin + src[begin..].find(CUSTOM_SECTION_END).expect("Template should have a custom section");
+
+    format!("{}{}{}", &src[..begin], custom, &src[end..])
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::{cli, str};
+    use super::super::tests::scaffold_workspace;
+
+    #[test]
+    fn test_sync_traits() {
+        let workspace_path = scaffold_workspace("sync");
+        let test_src_path = workspace_path.join("derive").join("tests").join("my_trait.rs");
+
+        let result = crate::cmd::add_trait(cli::AddTraitCommand { module: cli::TraitCommand {
+                trait_name: str!("MyTrait"),
+                workspace_path: Some(workspace_path.to_owned()),
+                library_name: None
+            }}, true, false)
+            .and_then(|_| {
+                // hand-edit both the custom section and the generated section
+                let test_src = fs::read_to_string(&test_src_path)?
+                    .replace("// traitenum:custom:begin", "// traitenum:custom:begin\n    fn custom_helper() {}")
+                    .replace("MyEnum::Alpha.name()", "MyEnum::Alpha.stale_name()");
+                fs::write(&test_src_path, test_src)?;
+
+                crate::cmd::sync_traits(cli::SyncTraitCommand {
+                    workspace_path: Some(workspace_path.to_owned()),
+                    library_name: None
+                }, true)
+            })
+            .and_then(|_| Ok(fs::read_to_string(&test_src_path)?));
+
+        fs::remove_dir_all(&workspace_path).unwrap();
+        let test_src = result.unwrap();
+
+        // Should preserve the custom section
+        assert!(test_src.contains("// traitenum:custom:begin\n    fn custom_helper() {}\n    // traitenum:custom:end"),
+            "Custom section should survive sync: {}", test_src);
+        // Should refresh the generated section
+        assert!(test_src.contains("MyEnum::Alpha.name()") && !test_src.contains("stale_name"),
+            "Generated section should be refreshed by sync: {}", test_src);
+    }
+}
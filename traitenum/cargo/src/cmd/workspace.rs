@@ -1,6 +1,6 @@
 //! Handles all workspace commands (init, new).
  
-use std::{env, fs, process, path::{PathBuf, Path}};
+use std::{fs, process, path::{PathBuf, Path}};
 use anyhow::{self, Context};
 use convert_case::{self as case, Casing};
 
@@ -63,8 +63,9 @@ proc-macro = true
 purpose = "derive"
 "#;
 
+// `cargo traitenum trait add` lists each derive macro within the brackets
 const DERIVE_SRC_TEMPLATE: &'static str =
-r#"traitenum_lib::gen_require!(%{LIB_CRATE_NAME}%, %{DERIVE_CRATE_NAME}%);
+r#"traitenum_lib::gen_derive_crate!(%{LIB_CRATE_NAME}%, %{DERIVE_CRATE_NAME}%, []);
 "#;
 
 
@@ -140,8 +141,8 @@ fn add_enumtrait(library: &cli::WorkspaceCommand) -> anyhow::Result<()> {
 fn build_workspace(library: &cli::WorkspaceCommand) -> anyhow::Result<()> {
     let workspace_path = library.workspace_path.as_ref().unwrap();
 
-    env::set_current_dir(workspace_path)?;
     let output = process::Command::new("cargo")
+        .current_dir(workspace_path)
         .arg("build")
         .output()
         .context(lib::Errors::CargoRunError())?;
@@ -1,27 +1,39 @@
 use std::{env, path::{PathBuf, Path}};
+use anyhow::Context;
 use crate::{self as lib, cli, cmd, meta, str};
 
 pub fn init_workspace(mut args: cli::InitWorkspaceCommand, quiet: bool) -> anyhow::Result<()> {
-    // clarify to the user that library.lib_name and library_name are the same
-    // todo: remove lib_name from the common
-    if args.module.lib_name.is_some() {
-        lib::log_warn("Using preferred `<LIBRARY_NAME>` argument instead of `--lib-name`")
-    } else {
-        args.module.lib_name = Some(args.library_name.clone());
-    }
-
     if let Some(ref workspace_path) = args.module.workspace_path {
         if workspace_path.is_relative() {
             args.module.workspace_path = Some(PathBuf::from(env::current_dir().unwrap())
                 .join(workspace_path));
         }
-    } else {
+    } else if let Some(ref library_name) = args.library_name {
         args.module.workspace_path = Some(PathBuf::from(env::current_dir().unwrap())
-            .join(&args.library_name));
+            .join(library_name));
+    } else {
+        args.module.workspace_path = Some(PathBuf::from(env::current_dir().unwrap()));
+    }
+
+    // without a library name, infer one from the workspace directory
+    if args.library_name.is_none() {
+        let library_name = cmd::infer_library_name(args.module.workspace_path.as_ref().unwrap())
+            .context(lib::Errors::AmbiguousLibrary)?;
+        args.library_name = Some(library_name);
+    }
+
+    let library_name = args.library_name.clone().unwrap();
+
+    // clarify to the user that library.lib_name and library_name are the same
+    // todo: remove lib_name from the common
+    if args.module.lib_name.is_some() {
+        lib::log_warn("Using preferred `<LIBRARY_NAME>` argument instead of `--lib-name`")
+    } else {
+        args.module.lib_name = Some(library_name.clone());
     }
 
     if args.module.derive_name.is_none() {
-        args.module.derive_name = Some(format!("{}-{}", args.library_name, "derive"));
+        args.module.derive_name = Some(format!("{}-{}", library_name, "derive"));
     }
 
     // Throw an error if `new` should be used instead of `init`.
@@ -30,15 +42,19 @@ pub fn init_workspace(mut args: cli::InitWorkspaceCommand, quiet: bool) -> anyho
     let mut workspace_manifest = cmd::read_workspace_manifest(&workspace_manifest_filepath)?;
 
     lib::log(quiet, "Updating workspace ...");
-    update_workspace(&args, &mut workspace_manifest, &workspace_manifest_filepath)?;
-    lib::log(quiet, "Creating lib package ...");
-    super::make_lib(&args.module)?;
-    lib::log(quiet, "Creating derive package ...");
-    super::make_derive(&args.module)?;
-    lib::log(quiet, "Configuring lib package ...");
-    super::config_lib(&args.module)?;
-    lib::log(quiet, "Configuring derive package ...");
-    super::config_derive(&args.module)?;
+    if update_workspace(&args, &mut workspace_manifest, &workspace_manifest_filepath)? {
+        lib::log(quiet, "Creating lib package ...");
+        super::make_lib(&args.module)?;
+        lib::log(quiet, "Creating derive package ...");
+        super::make_derive(&args.module)?;
+        lib::log(quiet, "Configuring lib package ...");
+        super::config_lib(&args.module)?;
+        lib::log(quiet, "Configuring derive package ...");
+        super::config_derive(&args.module)?;
+    } else {
+        lib::log(quiet, "Library is already configured in the workspace ...");
+    }
+
     lib::log(quiet, "Building workspace ...");
     super::build_workspace(&args.module)?;
     lib::log(quiet, "Testing workspace ...");
@@ -48,28 +64,157 @@ pub fn init_workspace(mut args: cli::InitWorkspaceCommand, quiet: bool) -> anyho
     Ok(())
 }
 
+/// Adds the library and its packages to the workspace manifest. Returns false if the same library is already
+/// configured, in which case the manifest is left untouched. A library that shares only its name or one of its
+/// directories with an existing entry is a conflict.
 fn update_workspace(
     args: &cli::InitWorkspaceCommand,
     manifest: &mut toml::Value,
     workspace_manifest_filepath: &Path
-) -> anyhow::Result<()> {
-    let members_data = meta::toml_ensure_array(
-        "workspace.members", manifest, "", workspace_manifest_filepath)?;
-
-    members_data.push(toml::Value::String(args.module.lib_dir.to_owned()));
-    members_data.push(toml::Value::String(args.module.derive_dir.to_owned()));
-
+) -> anyhow::Result<bool> {
     let library_metadata = meta::toml_ensure_array(
         "workspace.metadata.traitenum.library", manifest, "", workspace_manifest_filepath)?;
 
     let mut library_table = toml::Table::new();
     library_table.insert(str!("derive-dir"), toml::Value::String(args.module.derive_dir.to_owned()));
     library_table.insert(str!("lib-dir"), toml::Value::String(args.module.lib_dir.to_owned()));
-    library_table.insert(str!("name"), toml::Value::String(args.library_name.to_owned()));
+    library_table.insert(str!("name"), toml::Value::String(args.library_name.as_ref().unwrap().to_owned()));
+
+    for existing_library in library_metadata.iter().filter_map(|library| library.as_table()) {
+        if *existing_library == library_table {
+            return Ok(false);
+        }
+
+        for key in ["name", "lib-dir", "derive-dir"] {
+            if existing_library.get(key) == library_table.get(key) {
+                anyhow::bail!(lib::Errors::ConflictingLibrary(
+                    library_table[key].as_str().unwrap().to_owned(), workspace_manifest_filepath.to_owned()))
+            }
+        }
+    }
 
     library_metadata.push(toml::Value::Table(library_table));
 
+    let members_data = meta::toml_ensure_array(
+        "workspace.members", manifest, "", workspace_manifest_filepath)?;
+
+    meta::toml_array_push_unique(members_data, toml::Value::String(args.module.lib_dir.to_owned()));
+    meta::toml_array_push_unique(members_data, toml::Value::String(args.module.derive_dir.to_owned()));
+
     std::fs::write(workspace_manifest_filepath, toml::to_string_pretty(manifest).unwrap()).unwrap();
 
-    Ok(())
-}
\ No newline at end of file
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::{self as lib, cli, cmd, meta, str};
+
+    fn init_args(library_name: &str, lib_dir: &str, derive_dir: &str) -> cli::InitWorkspaceCommand {
+        cli::InitWorkspaceCommand {
+            library_name: Some(library_name.to_owned()),
+            module: cli::WorkspaceCommand {
+                workspace_path: None,
+                lib_name: Some(library_name.to_owned()),
+                derive_name: Some(format!("{}-derive", library_name)),
+                lib_dir: lib_dir.to_owned(),
+                derive_dir: derive_dir.to_owned(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_update_workspace_twice() {
+        let workspace_path = std::env::temp_dir()
+            .join(format!("cargo-traitenum-init-{}", std::process::id()));
+        fs::create_dir_all(&workspace_path).unwrap();
+        let manifest_filepath = workspace_path.join("Cargo.toml");
+        fs::write(&manifest_filepath, "[workspace]\nmembers = [\"lib\"]\n").unwrap();
+
+        let args = init_args("mylib", "lib", "derive");
+
+        // Should add the library on the first run and leave the manifest untouched on the second
+        for expected in [true, false] {
+            let mut manifest = cmd::read_workspace_manifest(&manifest_filepath).unwrap();
+            let updated = super::update_workspace(&args, &mut manifest, &manifest_filepath).unwrap();
+            assert_eq!(expected, updated);
+        }
+
+        let manifest = cmd::read_workspace_manifest(&manifest_filepath).unwrap();
+        let libraries = meta::toml_array("workspace.metadata.traitenum.library", &manifest, "", &manifest_filepath)
+            .unwrap();
+        assert_eq!(1, libraries.len(), "Library should be configured exactly once");
+        let members = meta::toml_array("workspace.members", &manifest, "", &manifest_filepath).unwrap();
+        assert_eq!(&vec![toml::Value::String(str!("lib")), toml::Value::String(str!("derive"))], members,
+            "Workspace members should not be duplicated");
+
+        // Should reject a library that reuses an existing library's directory
+        let mut manifest = cmd::read_workspace_manifest(&manifest_filepath).unwrap();
+        let result = super::update_workspace(
+            &init_args("otherlib", "lib", "otherlib-derive"), &mut manifest, &manifest_filepath);
+        let err = result.expect_err("Conflicting library should fail");
+        assert!(matches!(err.downcast_ref::<lib::Errors>(), Some(lib::Errors::ConflictingLibrary(dir, _)) if dir == "lib"),
+            "Unexpected error: {}", err);
+
+        fs::remove_dir_all(&workspace_path).unwrap();
+    }
+
+    // Initializes a library within an existing workspace twice, then checks that the second run was a no-op
+    #[test]
+    fn test_init_workspace_twice() {
+        let workspace_path = std::env::temp_dir()
+            .join(format!("cargo-traitenum-init-twice-{}", std::process::id()));
+        fs::create_dir_all(&workspace_path).unwrap();
+        let manifest_filepath = workspace_path.join("Cargo.toml");
+        fs::write(&manifest_filepath, "[workspace]\nmembers = []\nresolver = \"2\"\n").unwrap();
+
+        let args = || {
+            let mut args = init_args("mylib", "lib", "derive");
+            args.module.workspace_path = Some(workspace_path.to_owned());
+            args
+        };
+        let read_workspace = || -> anyhow::Result<Vec<String>> {
+            ["Cargo.toml", "lib/Cargo.toml", "lib/src/lib.rs", "derive/Cargo.toml", "derive/src/lib.rs"].iter()
+                .map(|filepath| Ok(fs::read_to_string(workspace_path.join(filepath))?))
+                .collect()
+        };
+
+        let result = super::init_workspace(args(), true)
+            .and_then(|_| read_workspace())
+            .and_then(|first| super::init_workspace(args(), true).map(|_| first))
+            .and_then(|first| Ok((first, read_workspace()?)));
+
+        fs::remove_dir_all(&workspace_path).unwrap();
+        let (first, second) = result.unwrap();
+        assert_eq!(first, second, "Initializing an already configured library should leave the workspace untouched");
+    }
+
+    // Initializes a library that reuses the directory of a library already configured in the workspace
+    #[test]
+    fn test_init_workspace_conflicting() {
+        let workspace_path = std::env::temp_dir()
+            .join(format!("cargo-traitenum-init-conflict-{}", std::process::id()));
+        fs::create_dir_all(&workspace_path).unwrap();
+        let manifest_filepath = workspace_path.join("Cargo.toml");
+        let manifest = concat!(
+            "[workspace]\nmembers = [\"lib\", \"derive\"]\n\n",
+            "[[workspace.metadata.traitenum.library]]\nname = \"mylib\"\nlib-dir = \"lib\"\nderive-dir = \"derive\"\n");
+        fs::write(&manifest_filepath, manifest).unwrap();
+
+        let mut args = init_args("otherlib", "lib", "otherlib-derive");
+        args.module.workspace_path = Some(workspace_path.to_owned());
+        let result = super::init_workspace(args, true);
+        let manifest_after = fs::read_to_string(&manifest_filepath).unwrap();
+        let created = workspace_path.join("otherlib-derive").exists();
+
+        fs::remove_dir_all(&workspace_path).unwrap();
+        let err = result.expect_err("Conflicting library should fail");
+        assert!(matches!(err.downcast_ref::<lib::Errors>(), Some(lib::Errors::ConflictingLibrary(dir, _)) if dir == "lib"),
+            "Unexpected error: {}", err);
+        assert!(err.to_string().contains("A different traitenum library is already configured for `lib`"),
+            "Unexpected error: {}", err);
+        assert_eq!(manifest, manifest_after, "A conflicting library should leave the manifest untouched");
+        assert!(!created, "A conflicting library should not be created");
+    }
+}
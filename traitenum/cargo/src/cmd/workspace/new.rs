@@ -93,3 +93,44 @@ fn make_workspace(args: &cli::NewWorkspaceCommand) -> anyhow::Result<()> {
     Ok(())
 }
 
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::{cli, str};
+
+    // Scaffolds a single-purpose library through the addon, which builds it and runs its derive test for the
+    // `Example` trait.
+    #[test]
+    fn test_new_workspace() {
+        let workspace_path = std::env::temp_dir()
+            .join(format!("cargo-traitenum-new-{}", std::process::id()));
+
+        let result = crate::cmd::new_workspace(cli::NewWorkspaceCommand {
+                workspace_name: str!("my-enums"),
+                library: cli::WorkspaceCommand {
+                    workspace_path: Some(workspace_path.to_owned()),
+                    lib_name: None,
+                    derive_name: None,
+                    lib_dir: str!("lib"),
+                    derive_dir: str!("derive")
+                }
+            }, true)
+            .and_then(|_| Ok(fs::read_to_string(workspace_path.join("derive").join("src").join("lib.rs"))?))
+            .and_then(|derive_src| crate::cmd::check_traits(cli::CheckTraitCommand {
+                    workspace_path: Some(workspace_path.to_owned()),
+                    library_name: None
+                }, true)
+                .map(|_| derive_src));
+
+        fs::remove_dir_all(&workspace_path).unwrap();
+        let derive_src = result.unwrap();
+
+        // Should generate the whole derive crate with a single gen_derive_crate!(), listing the example trait
+        assert!(derive_src.contains("gen_derive_crate!") && !derive_src.contains("gen_derive_macro!"),
+            "Derive crate should be generated by gen_derive_crate!(): {}", derive_src);
+        let derive_src: String = derive_src.split_whitespace().collect();
+        assert!(derive_src.contains("(Example,derive_traitenum_example,TRAITENUM_MODEL_BYTES_EXAMPLE)"),
+            "Derive crate should list the example trait: {}", derive_src);
+    }
+}
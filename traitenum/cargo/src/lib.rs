@@ -1,6 +1,6 @@
-use std::path::PathBuf;
+use std::{env, path::PathBuf};
 use anyhow;
-use colored::Colorize;
+use colored::{Color, Colorize};
 use thiserror;
 
 pub mod meta;
@@ -8,6 +8,10 @@ pub mod cli;
 pub mod cmd;
 
 const LOG_PREFIX: &'static str = "[traitenum] ";
+/// Overrides [LOG_PREFIX], e.g., when embedded in a larger tool
+const LOG_PREFIX_ENV: &'static str = "TRAITENUM_LOG_PREFIX";
+/// See: https://no-color.org
+const NO_COLOR_ENV: &'static str = "NO_COLOR";
 
 /// Converts a string literal into a String
 #[macro_export]
@@ -16,22 +20,37 @@ macro_rules! str { ($s:literal) => { String::from($s) }; }
 /// Logs to stdout, if not quiet
 pub fn log(quiet: bool, msg: &str) {
     if !quiet {
-        println!("{}{}", LOG_PREFIX.cyan(), msg);
+        println!("{}", format_log(Color::Cyan, msg));
     }
 }
 
 /// Logs a warning to stderr
 pub fn log_warn(msg: &str) {
-    eprintln!("{}{}", LOG_PREFIX.yellow(), msg);
+    eprintln!("{}", format_log(Color::Yellow, msg));
 }
 
 /// Logs to stdout, if not quiet
 pub fn log_success(quiet: bool, msg: &str) {
     if !quiet {
-        println!("{}{}", LOG_PREFIX.green(), msg);
+        println!("{}", format_log(Color::Green, msg));
     }
 }
 
+/// Logs an error to stderr
+pub fn log_error(msg: &str) {
+    eprintln!("{}", format_log(Color::Red, msg));
+}
+
+/// Disables ANSI colors for all subsequent logging
+pub fn disable_color() {
+    colored::control::set_override(false);
+}
+
+fn format_log(color: Color, msg: &str) -> String {
+    let prefix = env::var(LOG_PREFIX_ENV).unwrap_or_else(|_| LOG_PREFIX.to_owned());
+    format!("{}{}", prefix.color(color), msg)
+}
+
 /// Errors for the cargo addon
 #[derive(Debug, thiserror::Error)]
 pub enum Errors {
@@ -43,14 +62,20 @@ pub enum Errors {
     DuplicateTrait(String, String),
     #[error("Trait does not exist in library `{1}`: {0}")]
     UnknownTrait(String, String),
+    #[error("Derive macro has no matching trait in library `{1}` (Remove it from the derive package): {0}")]
+    OrphanedDeriveMacro(String, String),
+    #[error("Trait has no matching derive macro in library `{1}`: {0}")]
+    MissingDeriveMacro(String, String),
     #[error("Misconfigured cargo metadata: {0}")]
     MisconfiguredCargoMetadata(String),
-    #[error("Missing --library-name argument (Multiple libraries exist)")]
+    #[error("Missing library name argument (Unable to infer it from the directory)")]
     AmbiguousLibrary,
     #[error("Library not found: {0}")]
     LibraryNotFound(String),
     #[error("A cargo manifest already exists for path (Try `init` to add workspace members): {0}")]
     CargoManifestExists(PathBuf),
+    #[error("A different traitenum library is already configured for `{0}` in cargo manifest: {1}")]
+    ConflictingLibrary(String, PathBuf),
     #[error("A cargo manifest cannot be found for path: {0}")]
     NoCargoManifestExists(PathBuf),
     #[error("Invalid metadata for `{0}` in cargo manifest dir: {1}")]
@@ -67,18 +92,28 @@ pub enum Errors {
     CargoManifestNotWorkspace(PathBuf),
     #[error("Unable to run command: cargo")]
     CargoRunError(),
-    #[error("Unable to run command: rustfmt")]
-    RustfmtRunError(),
+    #[error("Command `rustfmt` failed: {0}")]
+    RustfmtRunError(String),
     #[error("Command `cargo new` failed: {0}")]
     CargoNewError(String),
     #[error("Command `cargo add` failed for `{0}`: {1}")]
     CargoAddError(String, String),
     #[error("Command `cargo {0}` failed")]
     CargoError(String),
+    #[error("Unterminated custom section in generated source (Expected `{0}`): {1}")]
+    UnterminatedCustomSection(String, PathBuf),
+    #[error("Derive package does not depend upon lib package `{0}`: {1}")]
+    MissingLibDependency(String, PathBuf),
+    #[error("Derive package `gen_require!()` refers to `{1}` instead of lib crate `{0}`: {2}")]
+    MismatchedGenRequire(String, String, PathBuf),
 }
 
 /// Runs the program
 pub fn run(cli: cli::Cli) -> anyhow::Result<()> {
+    if cli.no_color || env::var_os(NO_COLOR_ENV).is_some_and(|no_color| !no_color.is_empty()) {
+        disable_color();
+    }
+
     match cli.module {
         cli::CommandModules::Workspace(module) => match module.command {
             cli::WorkspaceCommands::New(args) => cmd::new_workspace(args, cli.quiet),
@@ -87,6 +122,23 @@ pub fn run(cli: cli::Cli) -> anyhow::Result<()> {
         cli::CommandModules::Trait(module) => match module.command {
             cli::TraitCommands::Add(args) => cmd::add_trait(args, cli.quiet, true),
             cli::TraitCommands::Remove(args) => cmd::remove_trait(args, cli.quiet),
+            cli::TraitCommands::Sync(args) => cmd::sync_traits(args, cli.quiet),
+            cli::TraitCommands::Check(args) => cmd::check_traits(args, cli.quiet),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use colored::Color;
+
+    #[test]
+    fn test_log_no_color() {
+        super::disable_color();
+
+        // Should not emit ANSI escape sequences once color is disabled
+        let output = super::format_log(Color::Cyan, "Adding trait to lib package...");
+        assert!(!output.contains('\x1b'), "Output should not contain ANSI escape sequences: {:?}", output);
+        assert!(output.ends_with("Adding trait to lib package..."));
+    }
+}
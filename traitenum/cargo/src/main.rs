@@ -1,6 +1,5 @@
 use std::process;
 use clap::Parser;
-use colored::Colorize;
 use cargo_traitenum::cli;
 
 fn main() {
@@ -8,7 +7,7 @@ fn main() {
     match cargo_traitenum::run(cli) {
         Ok(_) => {},
         Err(e) => {
-            eprintln!("{}{}", "[traitenum] ".red(), e);
+            cargo_traitenum::log_error(&e.to_string());
             process::exit(1);
         }
     }
@@ -331,6 +331,16 @@ pub(crate) fn toml_ensure_array<'toml>(
     Ok(value.as_array_mut().unwrap())
 }
 
+/// Pushes a value onto a toml array unless an equal value is already present. Returns whether it was pushed.
+pub(crate) fn toml_array_push_unique(array: &mut toml::value::Array, value: toml::Value) -> bool {
+    if array.contains(&value) {
+        false
+    } else {
+        array.push(value);
+        true
+    }
+}
+
 pub(crate) fn toml_str<'toml>(
     path: &str,
     toml: &'toml toml::Value,
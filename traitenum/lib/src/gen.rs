@@ -4,6 +4,8 @@ macro_rules! gen_require {
         use proc_macro;
         use proc_macro2;
         use $traitlib_path as traitlib;
+        // lets derived enums refer back to the lib crate, e.g., for its model bytes
+        const TRAITLIB_PATH: &'static str = stringify!($traitlib_path);
     };
 }
 
@@ -12,10 +14,38 @@ macro_rules! gen_derive_macro {
     ($derive_name:ident, $derive_func:ident, $model_bytes_path:path) => {
         #[proc_macro_derive($derive_name, attributes(traitenum))]
         pub fn $derive_func(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
-            match traitenum_lib::macros::traitenum_derive_macro(proc_macro2::TokenStream::from(item), $model_bytes_path) {
+            match traitenum_lib::macros::traitenum_derive_macro_for_lib(
+                    proc_macro2::TokenStream::from(item), $model_bytes_path, TRAITLIB_PATH, None) {
                 Ok(token_stream) => proc_macro::TokenStream::from(token_stream),
                 Err(err) => proc_macro::TokenStream::from(err.to_compile_error())
             }
         }        
     };
-}
\ No newline at end of file
+    // the trait path overrides the trait name in the model, for when the trait isn't in scope under its own name
+    ($derive_name:ident, $derive_func:ident, $model_bytes_path:path, $trait_path:path) => {
+        #[proc_macro_derive($derive_name, attributes(traitenum))]
+        pub fn $derive_func(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+            match traitenum_lib::macros::traitenum_derive_macro_for_lib(
+                    proc_macro2::TokenStream::from(item), $model_bytes_path, TRAITLIB_PATH, Some(stringify!($trait_path))) {
+                Ok(token_stream) => proc_macro::TokenStream::from(token_stream),
+                Err(err) => proc_macro::TokenStream::from(err.to_compile_error())
+            }
+        }
+    };
+}
+
+/// Generates an entire derive crate: the `gen_require!()` preamble followed by a `gen_derive_macro!()` for each
+/// `(derive name, derive function, model bytes const)` tuple. The model bytes consts are exported by the lib crate.
+/// A trait path may be appended to a tuple to override the trait that the derived enum implements.
+///
+/// `cargo traitenum` scaffolds derive crates with this macro, adding and removing tuples as traits come and go.
+#[macro_export]
+macro_rules! gen_derive_crate {
+    ($traitlib_path:path, $macrolib_path:path,
+            [$(($derive_name:ident, $derive_func:ident, $model_bytes_ident:ident $(, $trait_path:path)?)),* $(,)?]) => {
+        $crate::gen_require!($traitlib_path, $macrolib_path);
+        $(
+            $crate::gen_derive_macro!($derive_name, $derive_func, traitlib::$model_bytes_ident $(, $trait_path)?);
+        )*
+    };
+}
@@ -3,12 +3,12 @@ const MODEL_BYTES_NAME: &'static str = "TRAITENUM_MODEL_BYTES_";
 mod traitenum;
 mod enumtrait;
 
-pub use traitenum::traitenum_derive_macro;
+pub use traitenum::{traitenum_derive_macro, traitenum_derive_macro_for_lib};
 pub use enumtrait::enumtrait_macro;
 
 #[cfg(test)]
 mod tests {
-    use quote;
+    use quote::{self, ToTokens};
     use crate::{TRAIT_ATTRIBUTE_HELPER_NAME, model, macros::enumtrait, macros::traitenum};
 
 
@@ -115,6 +115,495 @@ mod tests {
         assert_traitenum_value_enum!(enum_model, "Four", "enum_default", "RPS::Scissors");
     }
     
+    #[test]
+    fn test_parse_enumtrait_byte() {
+        let attribute_src = quote::quote!{};
+
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                // test u8 serial preset w/start and increment
+                #[enumtrait::Num(preset(Serial), start(10), increment(5))]
+                fn byte_preset_serial(&self) -> u8;
+
+                // test u8 default
+                #[enumtrait::Num(default(255))]
+                fn byte_default(&self) -> u8;
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(attribute_src, item_src).unwrap().model;
+
+        let item_src = quote::quote!{
+            enum MyEnum {
+                One,
+                Two,
+                // test byte literal value
+                #[traitenum(byte_default(b'x'))]
+                Three,
+                // test integer literal value
+                #[traitenum(byte_preset_serial(7))]
+                Four,
+            }
+        };
+
+        let model_bytes = bincode::serialize(&model).unwrap();
+        let traitenum::TraitEnumMacroOutput {model: enum_model, tokens: _} = traitenum::parse_traitenum_macro(
+            item_src, &model_bytes).unwrap();
+
+        // test u8 preset(serial) w/start(10), increment(5)
+        assert_traitenum_value!(enum_model, "One", "byte_preset_serial", Byte, 10);
+        assert_traitenum_value!(enum_model, "Two", "byte_preset_serial", Byte, 15);
+        assert_traitenum_value!(enum_model, "Three", "byte_preset_serial", Byte, 20);
+        assert_traitenum_value!(enum_model, "Four", "byte_preset_serial", Byte, 7);
+        // test u8 default
+        assert_traitenum_value!(enum_model, "One", "byte_default", Byte, 255);
+        assert_traitenum_value!(enum_model, "Three", "byte_default", Byte, b'x');
+
+        // test error: out of range byte setting
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Num(preset(Serial), start(256), increment(1))]
+                fn byte_preset_serial(&self) -> u8;
+            }
+        };
+
+        assert!(enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).is_err(),
+            "Out of range u8 settings should throw an Error");
+    }
+    
+    #[test]
+    fn test_parse_traitenum_ordinals() {
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Str(preset(Variant))]
+                fn name(&self) -> &'static str;
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).unwrap().model;
+
+        let item_src = quote::quote!{
+            enum MyEnum {
+                One,
+                #[traitenum(name("two"))]
+                Two,
+                Three,
+            }
+        };
+
+        let model_bytes = bincode::serialize(&model).unwrap();
+        let traitenum::TraitEnumMacroOutput {model: enum_model, tokens: _} = traitenum::parse_traitenum_macro(
+            item_src, &model_bytes).unwrap();
+
+        // ordinals should be 0-based and contiguous, in order of declaration
+        let ordinals: Vec<usize> = enum_model.variants().iter().map(|v| v.ordinal()).collect();
+        assert_eq!(vec![0, 1, 2], ordinals);
+        assert_eq!(Some(1), enum_model.ordinal_of("Two"));
+        assert_eq!(None, enum_model.ordinal_of("Four"));
+
+        // ordinals should survive (de)serialization
+        let enum_model_bytes = bincode::serialize(&enum_model).unwrap();
+        let enum_model: model::TraitEnum = bincode::deserialize(&enum_model_bytes).unwrap();
+        assert_eq!(Some(2), enum_model.ordinal_of("Three"));
+    }
+    
+    #[test]
+    fn test_parse_traitenum_enum_defaults() {
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Str(preset(Variant))]
+                fn name(&self) -> &'static str;
+                #[enumtrait::Str(default("misc"))]
+                fn category(&self) -> &'static str;
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).unwrap().model;
+
+        let item_src = quote::quote!{
+            #[traitenum(category("core"))]
+            enum MyEnum {
+                One,
+                #[traitenum(category("extra"))]
+                Two,
+            }
+        };
+
+        let model_bytes = bincode::serialize(&model).unwrap();
+        let traitenum::TraitEnumMacroOutput {model: enum_model, tokens: _} = traitenum::parse_traitenum_macro(
+            item_src, &model_bytes).unwrap();
+
+        // the enum-level default should take precedence over the definition's own default
+        assert_eq!(&model::Value::StaticStr("core".to_owned()),
+            enum_model.variant("One").unwrap().value("category").unwrap().value());
+        // variants may still override the enum-level default
+        assert_eq!(&model::Value::StaticStr("extra".to_owned()),
+            enum_model.variant("Two").unwrap().value("category").unwrap().value());
+
+        // test error: duplicate enum-level defaults
+        let item_src = quote::quote!{
+            #[traitenum(category("core"), category("extra"))]
+            enum MyEnum {
+                One,
+            }
+        };
+
+        assert!(traitenum::parse_traitenum_macro(item_src, &model_bytes).is_err(),
+            "Duplicate enum-level attributes should throw an Error");
+    }
+
+    #[test]
+    fn test_parse_enumtrait_string_affixes() {
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Str(preset(Prefixed("ROLE_")))]
+                fn role(&self) -> &'static str;
+                #[enumtrait::Str(preset(Suffixed("_v1")))]
+                fn version(&self) -> &'static str;
+                #[enumtrait::Str(preset(UpperSnake), prefix("ROLE_"), suffix("!"))]
+                fn shout(&self) -> &'static str;
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).unwrap().model;
+
+        let item_src = quote::quote!{
+            enum MyEnum {
+                Alpha,
+                BravoCharlie,
+            }
+        };
+
+        let model_bytes = bincode::serialize(&model).unwrap();
+        let traitenum::TraitEnumMacroOutput {model: enum_model, tokens: _} = traitenum::parse_traitenum_macro(
+            item_src, &model_bytes).unwrap();
+
+        let value = |variant: &str, method: &str| enum_model.variant(variant).unwrap().value(method).unwrap().value();
+        assert_eq!(&model::Value::StaticStr("ROLE_Alpha".to_owned()), value("Alpha", "role"));
+        assert_eq!(&model::Value::StaticStr("Alpha_v1".to_owned()), value("Alpha", "version"));
+        // affix settings compose with a case conversion preset
+        assert_eq!(&model::Value::StaticStr("ROLE_BRAVO_CHARLIE!".to_owned()), value("BravoCharlie", "shout"));
+
+        // test error: affix setting without a preset
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Str(prefix("ROLE_"))]
+                fn role(&self) -> &'static str;
+            }
+        };
+
+        assert!(enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).is_err(),
+            "A prefix without a preset should throw an Error");
+    }
+
+    #[test]
+    fn test_parse_traitenum_geometric_preset() {
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Num(preset(Geometric), start(1), factor(2))]
+                fn flag(&self) -> u8;
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).unwrap().model;
+
+        let item_src = quote::quote!{
+            enum MyEnum {
+                One,
+                Two,
+                Three,
+                Four,
+            }
+        };
+
+        let model_bytes = bincode::serialize(&model).unwrap();
+        let traitenum::TraitEnumMacroOutput {model: enum_model, tokens: _} = traitenum::parse_traitenum_macro(
+            item_src, &model_bytes).unwrap();
+
+        let values: Vec<&model::Value> = ["One", "Two", "Three", "Four"].iter()
+            .map(|variant| enum_model.variant(variant).unwrap().value("flag").unwrap().value())
+            .collect();
+        assert_eq!(vec![&model::Value::Byte(1), &model::Value::Byte(2), &model::Value::Byte(4), &model::Value::Byte(8)],
+            values);
+
+        // test error: missing factor
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Num(preset(Geometric), start(1))]
+                fn flag(&self) -> u8;
+            }
+        };
+
+        assert!(enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).is_err(),
+            "A Geometric preset without a factor should throw an Error");
+
+        // test error: overflow
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Num(preset(Geometric), start(100), factor(2))]
+                fn flag(&self) -> u8;
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).unwrap().model;
+        let model_bytes = bincode::serialize(&model).unwrap();
+
+        let item_src = quote::quote!{
+            enum MyEnum {
+                One,
+                Two,
+                Three,
+            }
+        };
+
+        let err = traitenum::parse_traitenum_macro(item_src, &model_bytes).unwrap_err();
+        assert!(err.to_string().contains("out of range for `u8`: Three"),
+            "A Geometric preset that overflows should throw an Error: {}", err);
+    }
+
+    #[test]
+    fn test_parse_traitenum_ordinal_overflow() {
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Num(preset(Ordinal))]
+                fn ordinal(&self) -> u8;
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).unwrap().model;
+        let model_bytes = bincode::serialize(&model).unwrap();
+
+        // ordinals 0 through 255 fit within a u8
+        let variant_idents: Vec<syn::Ident> = (0..=255usize)
+            .map(|n| quote::format_ident!("Variant{}", n))
+            .collect();
+
+        let item_src = quote::quote!{
+            enum MyEnum {
+                #(#variant_idents),*
+            }
+        };
+
+        let traitenum::TraitEnumMacroOutput {model: enum_model, tokens: _} = traitenum::parse_traitenum_macro(
+            item_src, &model_bytes).unwrap();
+        assert_eq!(&model::Value::Byte(255), enum_model.variant("Variant255").unwrap().value("ordinal").unwrap().value());
+
+        // test error: the 257th variant overflows
+        let item_src = quote::quote!{
+            enum MyEnum {
+                #(#variant_idents),*,
+                Variant256
+            }
+        };
+
+        let err = traitenum::parse_traitenum_macro(item_src, &model_bytes).unwrap_err();
+        assert!(err.to_string().contains("out of range for `u8`: Variant256"),
+            "An Ordinal preset that overflows should throw an Error: {}", err);
+    }
+
+    #[test]
+    fn test_parse_traitenum_descending_serial() {
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Num(preset(Serial), start(10), increment(3), descending(true))]
+                fn rank(&self) -> u32;
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).unwrap().model;
+        let model_bytes = bincode::serialize(&model).unwrap();
+
+        let item_src = quote::quote!{
+            enum MyEnum {
+                One,
+                Two,
+                Three,
+                Four,
+            }
+        };
+
+        let traitenum::TraitEnumMacroOutput {model: enum_model, tokens: _} = traitenum::parse_traitenum_macro(
+            item_src.clone(), &model_bytes).unwrap();
+
+        assert_traitenum_value!(enum_model, "One", "rank", UnsignedInteger32, 10);
+        assert_traitenum_value!(enum_model, "Two", "rank", UnsignedInteger32, 7);
+        assert_traitenum_value!(enum_model, "Four", "rank", UnsignedInteger32, 1);
+
+        // test error: underflow
+        let underflow_item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Num(preset(Serial), start(5), increment(3), descending(true))]
+                fn rank(&self) -> u32;
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(quote::quote!{}, underflow_item_src).unwrap().model;
+        let model_bytes = bincode::serialize(&model).unwrap();
+
+        let err = traitenum::parse_traitenum_macro(item_src, &model_bytes).unwrap_err();
+        assert!(err.to_string().contains("out of range for `u32`: Three"),
+            "A descending Serial preset that underflows should throw an Error: {}", err);
+
+        // test error: descending without a Serial preset
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Num(preset(Ordinal), descending(true))]
+                fn rank(&self) -> u32;
+            }
+        };
+
+        assert!(enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).is_err(),
+            "The descending setting without a Serial preset should throw an Error");
+    }
+
+    #[test]
+    fn test_parse_traitenum_serial_modulo() {
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Num(preset(Serial), start(0), increment(5), modulo(12))]
+                fn hour(&self) -> u32;
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).unwrap().model;
+        let model_bytes = bincode::serialize(&model).unwrap();
+
+        let item_src = quote::quote!{
+            enum MyEnum {
+                One,
+                Two,
+                Three,
+                Four,
+            }
+        };
+
+        let traitenum::TraitEnumMacroOutput {model: enum_model, tokens: _} = traitenum::parse_traitenum_macro(
+            item_src, &model_bytes).unwrap();
+
+        assert_traitenum_value!(enum_model, "One", "hour", UnsignedInteger32, 0);
+        assert_traitenum_value!(enum_model, "Two", "hour", UnsignedInteger32, 5);
+        assert_traitenum_value!(enum_model, "Three", "hour", UnsignedInteger32, 10);
+        assert_traitenum_value!(enum_model, "Four", "hour", UnsignedInteger32, 3);
+
+        // test error: zero modulo
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Num(preset(Serial), start(0), increment(5), modulo(0))]
+                fn hour(&self) -> u32;
+            }
+        };
+
+        let err = enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).unwrap_err();
+        assert!(err.to_string().contains("must be nonzero"),
+            "A zero modulo should throw an Error: {}", err);
+    }
+
+    #[test]
+    fn test_parse_traitenum_float_constants() {
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Num(default(f64::INFINITY))]
+                fn weight(&self) -> f64;
+                #[enumtrait::Num(default(f32::NAN))]
+                fn ratio(&self) -> f32;
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).unwrap().model;
+        // NaN settings should not break model equality
+        let model_bytes = bincode::serialize(&model).unwrap();
+        assert_eq!(model, model::EnumTrait::deserialize(&model_bytes).unwrap());
+
+        let item_src = quote::quote!{
+            enum MyEnum {
+                One,
+                #[traitenum(weight(f64::NEG_INFINITY), ratio(0.5))]
+                Two,
+            }
+        };
+
+        let traitenum::TraitEnumMacroOutput {model: enum_model, tokens: enum_tokens} = traitenum::parse_traitenum_macro(
+            item_src, &model_bytes).unwrap();
+
+        assert_traitenum_value!(enum_model, "One", "weight", Float64, f64::INFINITY);
+        assert_traitenum_value!(enum_model, "Two", "weight", Float64, f64::NEG_INFINITY);
+        assert_traitenum_value!(enum_model, "Two", "ratio", Float32, 0.5);
+        // NaN values should compare equal to themselves, but not to other floats
+        assert_eq!(&model::Value::Float32(f32::NAN), enum_model.variant("One").unwrap().value("ratio").unwrap().value());
+        assert_ne!(&model::Value::Float32(f32::NAN), enum_model.variant("Two").unwrap().value("ratio").unwrap().value());
+
+        let enum_model_bytes = bincode::serialize(&enum_model).unwrap();
+        let deserialized: model::TraitEnum = bincode::deserialize(&enum_model_bytes).unwrap();
+        assert_eq!(enum_model, deserialized);
+
+        // non-finite floats have no literal form
+        let enum_tokens = enum_tokens.to_string();
+        assert!(enum_tokens.contains(":: core :: f32 :: NAN") && enum_tokens.contains(":: core :: f64 :: NEG_INFINITY"),
+            "Non-finite floats should be written as constants: {}", enum_tokens);
+
+        // test error: mismatched float type
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Num(default(f32::INFINITY))]
+                fn weight(&self) -> f64;
+            }
+        };
+
+        assert!(enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).is_err(),
+            "A float constant of the wrong type should throw an Error");
+    }
+
+    #[test]
+    fn test_parse_enumtrait_number_ranges() {
+        // test max default for u8
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Num(default(255))]
+                fn byte_default(&self) -> u8;
+            }
+        };
+
+        assert!(enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).is_ok(),
+            "In range u8 default should be accepted");
+
+        // test error: out of range default for u8
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Num(default(256))]
+                fn byte_default(&self) -> u8;
+            }
+        };
+
+        let err = enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).unwrap_err();
+        assert!(err.to_string().contains("`u8` (0..=255)"),
+            "Out of range u8 default should throw an Error naming the type and its bounds");
+
+        // test error: negative default for an unsigned type
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Num(default(-1))]
+                fn column(&self) -> u32;
+            }
+        };
+
+        assert!(enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).is_err(),
+            "Negative u32 default should throw an Error");
+
+        // test signed and float defaults
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Num(default(-2147483648))]
+                fn signed_default(&self) -> i32;
+
+                #[enumtrait::Num(default(1.5))]
+                fn float_default(&self) -> f32;
+            }
+        };
+
+        assert!(enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).is_ok(),
+            "In range i32 and f32 defaults should be accepted");
+    }
+    
     #[test]
     fn test_parse_enumtrait_boxed_trait_relations() {
         let attribute_src = quote::quote!{};
@@ -135,15 +624,36 @@ mod tests {
 
                 // test elided Rel dynamic one-to-many
                 fn one_to_many_elided_dyn(&self) -> Box<dyn Iterator<Item = Box<dyn SecondManyTrait>>>;
+
+                // test fully elided Rel dynamic many-to-one
+                fn many_to_one_elided_dyn(&self) -> Box<dyn ThirdOneTrait>;
+
+                // test Rel dynamic one-to-one
+                #[enumtrait::Rel(nature(OneToOne))]
+                fn one_to_one_dyn(&self) -> Box<dyn FourthOneTrait>;
             }
         };
         
         let model = enumtrait::parse_enumtrait_macro(attribute_src, item_src).unwrap().model;
         dbg!(&model);
 
+        let relation_nature = |method_name: &str| {
+            model.method(method_name).unwrap()
+                .attribute_definition()
+                .get_relation_definition()
+                .nature()
+        };
+
+        assert_eq!(Some(model::RelationNature::ManyToOne), relation_nature("many_to_one_elided_dyn"),
+            "A fully elided Box<dyn Trait> relation should infer a ManyToOne nature");
+        assert_eq!(Some(model::RelationNature::OneToOne), relation_nature("one_to_one_dyn"),
+            "An explicit OneToOne nature should override the inferred nature");
+
         let item_src = quote::quote!{
             #[traitenum(many_to_one_dyn(ManyToOneEnum::Dyn))]
             #[traitenum(many_to_one_dyn_elide(ManyToOneEnum::DynElide))]
+            #[traitenum(many_to_one_elided_dyn(ManyToOneEnum::DynElided))]
+            #[traitenum(one_to_one_dyn(OneToOneEnum::Dyn))]
             enum MyEnum {
                 #[traitenum(one_to_many_dyn(OneToManyOneEnum), one_to_many_elided_dyn(OneToManyTwoEnum))]
                 One,
@@ -159,8 +669,155 @@ mod tests {
 
         dbg!(&enum_model);
         dbg!(&enum_tokens.to_string());
+
+        // each variant relates to a different enum, so there's no single concrete type to iterate
+        let enum_tokens = enum_tokens.to_string();
+        assert!(!enum_tokens.contains("one_to_many_dyn_typed") && !enum_tokens.contains("one_to_many_elided_dyn_typed"),
+            "A one-to-many relation with mixed targets should not have a typed companion: {}", enum_tokens);
+
+        let item_src = quote::quote!{
+            #[traitenum(many_to_one_dyn(ManyToOneEnum::Dyn))]
+            #[traitenum(many_to_one_dyn_elide(ManyToOneEnum::DynElide))]
+            #[traitenum(many_to_one_elided_dyn(ManyToOneEnum::DynElided))]
+            #[traitenum(one_to_one_dyn(OneToOneEnum::Dyn))]
+            enum MyEnum {
+                #[traitenum(one_to_many_dyn(OneToManyOneEnum), one_to_many_elided_dyn(OneToManyTwoEnum))]
+                One,
+                #[traitenum(one_to_many_dyn(OneToManyOneEnum), one_to_many_elided_dyn(OneToManyFourEnum))]
+                Two,
+            }
+        };
+
+        let enum_tokens = traitenum::parse_traitenum_macro(item_src, &model_bytes).unwrap().tokens.to_string();
+        assert!(enum_tokens.contains(
+                "pub fn one_to_many_dyn_typed (& self) -> impl :: std :: iter :: Iterator < Item = OneToManyOneEnum >"),
+            "A one-to-many relation with a single target should have a typed companion: {}", enum_tokens);
+        assert!(!enum_tokens.contains("one_to_many_elided_dyn_typed"),
+            "A one-to-many relation with mixed targets should not have a typed companion: {}", enum_tokens);
+    }
+
+    #[test]
+    fn test_parse_traitenum_many_to_one_overrides() {
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                fn parent(&self) -> Box<dyn ParentTrait>;
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).unwrap().model;
+        let model_bytes = bincode::serialize(&model).unwrap();
+
+        let item_src = quote::quote!{
+            #[traitenum(parent(FirstParentEnum::Default))]
+            enum MyEnum {
+                #[traitenum(parent(SecondParentEnum::Specific))]
+                One,
+                Two,
+            }
+        };
+
+        let traitenum::TraitEnumMacroOutput {model: enum_model, tokens: enum_tokens} = traitenum::parse_traitenum_macro(
+            item_src, &model_bytes).unwrap();
+
+        let parent = |variant_name: &str| {
+            enum_model.variant(variant_name).unwrap().value("parent").unwrap().to_token_stream().to_string()
+        };
+
+        assert_eq!("SecondParentEnum :: Specific", parent("One"), "A variant should override the enum-level target");
+        assert_eq!("FirstParentEnum :: Default", parent("Two"), "A variant should default to the enum-level target");
+        assert!(enum_tokens.to_string().contains(
+                "Self :: One => :: std :: boxed :: Box :: new (SecondParentEnum :: Specific)"),
+            "Each variant should dispatch to its own target: {}", enum_tokens);
+
+        // test error: a variant without a target, nor an enum-level default
+        let item_src = quote::quote!{
+            enum MyEnum {
+                #[traitenum(parent(SecondParentEnum::Specific))]
+                One,
+                Two,
+            }
+        };
+
+        let err = traitenum::parse_traitenum_macro(item_src, &model_bytes).unwrap_err();
+        assert!(err.to_string().contains("Missing value for relation `parent`: Two"),
+            "A variant without a relation target should throw an Error: {}", err);
     }
 
+    #[test]
+    fn test_parse_enumtrait_static_slice_relations() {
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                // test elided Rel static one-to-many
+                fn children(&self) -> &'static [crate::ChildEnum];
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).unwrap().model;
+        let reldef = model.method("children").unwrap().attribute_definition().get_relation_definition();
+        assert_eq!(Some(model::Dispatch::StaticSlice), reldef.dispatch());
+        assert_eq!(Some(model::RelationNature::OneToMany), reldef.nature(),
+            "A static slice relation should infer a OneToMany nature");
+
+        let item_src = quote::quote!{
+            enum MyEnum {
+                #[traitenum(children(ChildEnum))]
+                One,
+                #[traitenum(children(ChildEnum))]
+                Two,
+            }
+        };
+
+        let model_bytes = bincode::serialize(&model).unwrap();
+        let traitenum::TraitEnumMacroOutput {model: _, tokens: enum_tokens} = traitenum::parse_traitenum_macro(
+            item_src, &model_bytes).unwrap();
+
+        // the related enum's variants are returned as a slice, without boxing
+        let enum_tokens = enum_tokens.to_string();
+        assert!(enum_tokens.contains("fn children (& self) -> & 'static [crate :: ChildEnum] { ChildEnum :: VARIANTS }"),
+            "A static slice relation should return the related enum's variants: {}", enum_tokens);
+        assert!(!enum_tokens.contains("VARIANTS :"),
+            "A derived enum should not provide its variants without opting in: {}", enum_tokens);
+
+        // an opted in enum provides its variants as a slice, for other enums to relate to
+        let item_src = quote::quote!{
+            #[traitenum(variants)]
+            enum MyEnum {
+                #[traitenum(children(ChildEnum))]
+                One,
+                #[traitenum(children(ChildEnum))]
+                Two,
+            }
+        };
+
+        let traitenum::TraitEnumMacroOutput {model: enum_model, tokens: enum_tokens} = traitenum::parse_traitenum_macro(
+            item_src, &model_bytes).unwrap();
+        assert!(enum_model.variants_accessor());
+        assert!(enum_tokens.to_string().contains(
+                "pub const VARIANTS : & 'static [Self] = & [Self :: One , Self :: Two] ;"),
+            "An enum configured with `#[traitenum(variants)]` should provide its variants as a slice: {}", enum_tokens);
+
+        // test error: many-to-one static slice
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Rel(nature(ManyToOne))]
+                fn children(&self) -> &'static [ChildEnum];
+            }
+        };
+
+        assert!(enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).is_err(),
+            "A ManyToOne static slice relation should throw an Error");
+
+        // test error: explicit dispatch for a static slice
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Rel(dispatch(BoxedTrait))]
+                fn children(&self) -> &'static [ChildEnum];
+            }
+        };
+
+        assert!(enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).is_err(),
+            "An explicit dispatch for a static slice relation should throw an Error");
+    }
 
     #[test]
     fn test_parse_enumtrait_errors() {
@@ -182,19 +839,16 @@ mod tests {
         assert!(enumtrait::parse_enumtrait_macro(attribute_src, simple_item_src.clone()).is_err(),
             "Mismatched trait name and #[{}(<pathspec>)] identifier should throw an Error", TRAIT_ATTRIBUTE_HELPER_NAME);
 
-        let unimplemented_static_dispatch_src = quote::quote!{
+        let unknown_dispatch_src = quote::quote!{
             pub trait MyTrait {
-                type ManyType: ManyTrait;
-
-                #[traitenum::Rel(dispatch(Other))]
-                fn many_to_one(&self) -> Self::ManyType;
+                #[enumtrait::Rel(dispatch(Other))]
+                fn many_to_one(&self) -> Box<dyn ManyTrait>;
             }
         };
 
-        assert!(enumtrait::parse_enumtrait_macro(
-            simple_attribute_src.clone(),
-            unimplemented_static_dispatch_src).is_err(),
-            "Dispatch::Other is permanently unimplemented and should throw an Error");
+        let err = enumtrait::parse_enumtrait_macro(simple_attribute_src.clone(), unknown_dispatch_src).unwrap_err();
+        assert!(err.to_string().contains("Unknown dispatch: Other"),
+            "Dispatch other than BoxedTrait is unsupported and should throw an Error: {}", err);
 
         let associated_types_src = quote::quote!{
             pub trait MyTrait {
@@ -204,9 +858,180 @@ mod tests {
             }
         };
 
-        assert!(enumtrait::parse_enumtrait_macro(
-            simple_attribute_src.clone(),
-            associated_types_src).is_err(),
-            "Associated types are not supported");
+        let err = enumtrait::parse_enumtrait_macro(simple_attribute_src.clone(), associated_types_src).unwrap_err();
+        assert!(err.to_string().contains("Return a `Box<dyn Trait>` to model a relation instead"),
+            "Associated types should throw an Error with guidance: {}", err);
+
+        let associated_type_return_src = quote::quote!{
+            pub trait MyTrait {
+                fn many_to_one(&self) -> Self::ManyType;
+            }
+        };
+
+        let err = enumtrait::parse_enumtrait_macro(simple_attribute_src.clone(), associated_type_return_src).unwrap_err();
+        assert!(err.to_string().contains("Associated types are not supported"),
+            "Associated type return types should throw an Error with guidance: {}", err);
+
+        let display_item_src = quote::quote!{
+            pub trait MyTrait {
+                fn name(&self) -> &'static str;
+                fn column(&self) -> usize;
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(quote::quote!{ display_from = "name" }, display_item_src.clone())
+            .unwrap().model;
+        assert_eq!(Some("name"), model.display_from());
+
+        // test error: display_from refers to an unknown method
+        let err = enumtrait::parse_enumtrait_macro(quote::quote!{ display_from = "title" }, display_item_src.clone())
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown method for `display_from`: title"),
+            "An unknown display_from method should throw an Error: {}", err);
+
+        // test error: display_from refers to a method that does not return a string
+        let err = enumtrait::parse_enumtrait_macro(quote::quote!{ display_from = "column" }, display_item_src)
+            .unwrap_err();
+        assert!(err.to_string().contains("must return `&'static str`: column"),
+            "A non-string display_from method should throw an Error: {}", err);
+    }
+
+    #[test]
+    fn test_parse_traitenum_enumtrait_model() {
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Str(preset(Variant))]
+                fn name(&self) -> &'static str;
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).unwrap().model;
+        let model_bytes = bincode::serialize(&model).unwrap();
+        let traitlib_path: syn::Path = syn::parse_quote!(my_lib);
+
+        // the accessor is opt-in
+        let traitenum::TraitEnumMacroOutput {model: enum_model, tokens} = traitenum::parse_traitenum_macro_for_trait(
+            quote::quote!{ enum MyEnum { One } }, &model_bytes, None, Some(&traitlib_path)).unwrap();
+        assert!(!enum_model.enumtrait_model_accessor());
+        assert!(!tokens.to_string().contains("enumtrait_model"),
+            "The enumtrait_model() accessor should not be generated without opting in: {}", tokens);
+
+        // the accessor deserializes the lib crate's model bytes rather than embedding its own copy
+        let traitenum::TraitEnumMacroOutput {model: enum_model, tokens} = traitenum::parse_traitenum_macro_for_trait(
+            quote::quote!{
+                #[traitenum(enumtrait_model)]
+                enum MyEnum { One }
+            }, &model_bytes, None, Some(&traitlib_path)).unwrap();
+        assert!(enum_model.enumtrait_model_accessor());
+        assert!(tokens.to_string().contains("deserialize (my_lib :: TRAITENUM_MODEL_BYTES_MY_TRAIT)"),
+            "The enumtrait_model() accessor should refer to the lib crate's model bytes: {}", tokens);
+
+        // test error: opting in without the lib crate's path
+        let err = traitenum::parse_traitenum_macro(quote::quote!{
+            #[traitenum(enumtrait_model)]
+            enum MyEnum { One }
+        }, &model_bytes).unwrap_err();
+        assert!(err.to_string().contains("requires a derive crate that calls `gen_require!()`"),
+            "Opting in without the lib crate's path should throw an Error: {}", err);
+    }
+
+    #[test]
+    fn test_parse_traitenum_try_from() {
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Str(preset(Kebab))]
+                fn name(&self) -> &'static str;
+                #[enumtrait::Num(preset(Ordinal))]
+                fn column(&self) -> usize;
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).unwrap().model;
+        let model_bytes = bincode::serialize(&model).unwrap();
+
+        let item_src = quote::quote!{
+            #[traitenum(try_from = "name")]
+            enum MyEnum {
+                AlphaOne,
+                BravoTwo,
+            }
+        };
+
+        let traitenum::TraitEnumMacroOutput {model: enum_model, tokens} = traitenum::parse_traitenum_macro(
+            item_src, &model_bytes).unwrap();
+        assert_eq!(Some("name"), enum_model.try_from_method());
+        assert!(tokens.to_string().contains("TryFrom < & str > for MyEnum"),
+            "A TryFrom<&str> implementation should be generated: {}", tokens);
+
+        // test error: try_from refers to an unknown method
+        let err = traitenum::parse_traitenum_macro(quote::quote!{
+            #[traitenum(try_from = "title")]
+            enum MyEnum { One }
+        }, &model_bytes).unwrap_err();
+        assert!(err.to_string().contains("Unknown method for `try_from`: title"),
+            "An unknown try_from method should throw an Error: {}", err);
+
+        // test error: try_from refers to a method that does not return a string
+        let err = traitenum::parse_traitenum_macro(quote::quote!{
+            #[traitenum(try_from = "column")]
+            enum MyEnum { One }
+        }, &model_bytes).unwrap_err();
+        assert!(err.to_string().contains("must return `&'static str`: column"),
+            "A non-string try_from method should throw an Error: {}", err);
+
+        // test error: two variants share a name
+        let err = traitenum::parse_traitenum_macro(quote::quote!{
+            #[traitenum(try_from = "name")]
+            enum MyEnum {
+                One,
+                #[traitenum(name("one"))]
+                Two
+            }
+        }, &model_bytes).unwrap_err();
+        assert!(err.to_string().contains("Duplicate value for `try_from` method `name`: Two"),
+            "Duplicate try_from values should throw an Error: {}", err);
+    }
+
+    #[test]
+    fn test_parse_traitenum_errors() {
+        let item_src = quote::quote!{
+            pub trait MyTrait {
+                #[enumtrait::Str(preset(Variant))]
+                fn name(&self) -> &'static str;
+            }
+        };
+
+        let model = enumtrait::parse_enumtrait_macro(quote::quote!{}, item_src).unwrap().model;
+        let model_bytes = bincode::serialize(&model).unwrap();
+
+        // test error: variants with fields
+        let tuple_variant_src = quote::quote!{
+            enum MyEnum {
+                One,
+                Two(usize),
+            }
+        };
+
+        let err = traitenum::parse_traitenum_macro(tuple_variant_src, &model_bytes).unwrap_err();
+        assert_eq!("[traitenum] traitenum supports only fieldless enums; variant `Two` has fields", err.to_string(),
+            "Variants with fields should throw a specific Error");
+
+        let struct_variant_src = quote::quote!{
+            enum MyEnum {
+                One { id: usize },
+            }
+        };
+
+        assert!(traitenum::parse_traitenum_macro(struct_variant_src, &model_bytes).is_err(),
+            "Struct variants should throw an Error");
+
+        // test error: enums without variants
+        let empty_src = quote::quote!{
+            enum Empty {}
+        };
+
+        let err = traitenum::parse_traitenum_macro(empty_src, &model_bytes).unwrap_err();
+        assert_eq!("[traitenum] traitenum requires at least one variant", err.to_string(),
+            "Enums without variants should throw a specific Error");
     }
-}
\ No newline at end of file
+}
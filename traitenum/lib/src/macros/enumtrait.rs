@@ -12,6 +12,9 @@ const IDENT_BOX: &'static str = "Box";
 const IDENT_ITERATOR: &'static str = "Iterator";
 const IDENT_ITEM: &'static str = "Item";
 const IDENT_SELF: &'static str = "Self";
+const ARG_DISPLAY_FROM: &'static str = "display_from";
+const ERR_ASSOCIATED_TYPES: &'static str =
+    "Associated types are not supported. Return a `Box<dyn Trait>` to model a relation instead";
 
 #[derive(Debug)]
 pub(crate) struct EnumTraitMacroOutput {
@@ -43,9 +46,7 @@ pub(crate) fn parse_enumtrait_macro(
     attr: proc_macro2::TokenStream,
     item: proc_macro2::TokenStream) -> syn::Result<EnumTraitMacroOutput>
 {
-    if !attr.is_empty() {
-        synerr!(attr, "Top-level #[enumtrait] does not accept arguments");
-    }
+    let display_from = parse_enumtrait_args(attr)?;
 
     let mut trait_input: syn::ItemTrait = syn::parse2(item)?;
     let identifier = model::Identifier::new(vec![], trait_input.ident.to_string());
@@ -57,21 +58,61 @@ pub(crate) fn parse_enumtrait_macro(
         match trait_item {
             // Build a model Method
             syn::TraitItem::Fn(func) => parse_trait_fn(&mut methods, func)?,
-            syn::TraitItem::Type(t) => synerr!(t, "Associated types are not supported"),
+            syn::TraitItem::Type(t) => synerr!(t, "{}", ERR_ASSOCIATED_TYPES),
             _ => ()
         }
     }
 
 
+    let mut model = model::EnumTrait::new(identifier, methods);
+
+    // The Display implementation delegates to a string method of the trait
+    if let Some(display_from) = display_from {
+        let method_name = display_from.value();
+        match model.method(&method_name) {
+            Some(method) if method.return_type() == model::ReturnType::StaticStr => {},
+            Some(_) => synerr!(&display_from, "Method for `{}` must return `{}`: {}",
+                ARG_DISPLAY_FROM, model::ReturnType::STATIC_STR, method_name),
+            None => synerr!(&display_from, "Unknown method for `{}`: {}", ARG_DISPLAY_FROM, method_name)
+        }
+
+        model = model.with_display_from(method_name);
+    }
+
     // Remove all #[tratienum] attributes from the TokenStream now that we're done parsing them
     clean_helper_attributes(&mut trait_input)?;
 
     Ok(EnumTraitMacroOutput {
         tokens: trait_input.to_token_stream(),
-        model: model::EnumTrait::new(identifier, methods)
+        model
     })
 }
 
+// Top-level arguments, e.g., #[enumtrait(display_from = "name")]
+fn parse_enumtrait_args(attr: proc_macro2::TokenStream) -> syn::Result<Option<syn::LitStr>> {
+    if attr.is_empty() {
+        return Ok(None);
+    }
+
+    let args = syn::parse::Parser::parse2(
+            syn::punctuated::Punctuated::<syn::MetaNameValue, syn::Token![,]>::parse_terminated, attr.clone())
+        .map_err(|_| mksynerr!(&attr, "Top-level #[enumtrait] only accepts: {} = \"<method name>\"", ARG_DISPLAY_FROM))?;
+
+    let mut display_from = None;
+    for arg in args {
+        if !arg.path.is_ident(ARG_DISPLAY_FROM) {
+            synerr!(&arg.path, "Unknown #[enumtrait] argument: {}", arg.path.to_token_stream());
+        }
+
+        match arg.value {
+            syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit_str), .. }) => display_from = Some(lit_str),
+            _ => synerr!(&arg.value, "Expected a method name for `{}`", ARG_DISPLAY_FROM)
+        }
+    }
+
+    Ok(display_from)
+}
+
 fn parse_trait_fn(methods: &mut Vec<model::Method>, func: &syn::TraitItemFn) -> syn::Result<()> {
     // ignore functions with default implementations
     if func.default.is_some() {
@@ -142,15 +183,7 @@ fn parse_trait_fn_return(func: &syn::TraitItemFn) -> syn::Result<(model::ReturnT
                     return_type = Some(ret_type);
                     return_type_identifier = Some(ret_type_id);
                 } else if path_type.path.segments[0].ident == IDENT_SELF {
-                    return_type = Some(model::ReturnType::AssociatedType);
-                    return_type_identifier = match model::Identifier::try_from(&path_type.path) {
-                        Ok(id) => Some(id),
-                        Err(_) => {
-                            synerr!(return_type,
-                                "Unsupported return type: {}",
-                                &path_type.path.to_token_stream().to_string())
-                        }
-                    }
+                    synerr!(path_type, "{}", ERR_ASSOCIATED_TYPES)
                 } else {
                     // Anything else is modeled as ReturnType::Type, including enums.
                     return_type = Some(model::ReturnType::Type);
@@ -164,7 +197,7 @@ fn parse_trait_fn_return(func: &syn::TraitItemFn) -> syn::Result<(model::ReturnT
                     }
                 }
             },
-            // As for reference return types, we only support &'static str at the moment.
+            // As for reference return types, we only support &'static str and &'static [Enum] at the moment.
             syn::Type::Reference(ref ref_type) => {
                 // only elided and static lifetimes are supported
                 let _has_static_lifetime = match &ref_type.lifetime {
@@ -192,6 +225,17 @@ fn parse_trait_fn_return(func: &syn::TraitItemFn) -> syn::Result<(model::ReturnT
                     }
                 }
 
+                // a slice of the related enum's variants: &'static [MyEnum]
+                if let syn::Type::Slice(ref slice_type) = *ref_type.elem {
+                    if let syn::Type::Path(ref path_type) = *slice_type.elem {
+                        let id = model::Identifier::try_from(&path_type.path)
+                            .map_err(|_| mksynerr!(path_type, "Unable to parse slice enum identifier"))?;
+
+                        return_type = Some(model::ReturnType::StaticSlice);
+                        return_type_identifier = Some(id);
+                    }
+                }
+
                 // ... the else statement for each nested if statement above
                 if return_type.is_none() {
                     synerr!(ref_type, "Unsupported return reference type")
@@ -1,6 +1,8 @@
+use std::collections::HashMap;
 use quote::{self, ToTokens};
 use syn;
 use proc_macro2;
+use convert_case::{self as case, Casing};
 
 use crate::{
     model, model::parse,
@@ -20,14 +22,43 @@ pub fn traitenum_derive_macro(
     let TraitEnumMacroOutput { tokens, model: _model } = parse_traitenum_macro(item, model_bytes)?;
     Ok(tokens)
 }
+
+/// Derives the enum with the path of the lib crate that exports its trait, as provided by `gen_require!()`.
+/// Generated items that refer back to the lib crate, such as the `enumtrait_model()` accessor, require it.
+/// This is the entry point of the macros generated by `gen_derive_macro!()`, which may also provide an explicit trait
+/// path to implement, rather than the trait's name as found in the model.
+pub fn traitenum_derive_macro_for_lib(
+    item: proc_macro2::TokenStream,
+    model_bytes: &[u8],
+    traitlib_path: &str,
+    trait_path: Option<&str>) -> Result<proc_macro2::TokenStream, syn::Error>
+{
+    let traitlib_path: syn::Path = syn::parse_str(traitlib_path)?;
+    let trait_path: Option<syn::Path> = trait_path.map(syn::parse_str).transpose()?;
+    let TraitEnumMacroOutput { tokens, model: _model } = parse_traitenum_macro_for_trait(
+        item, model_bytes, trait_path.as_ref(), Some(&traitlib_path))?;
+    Ok(tokens)
+}
  
 pub(crate) fn parse_traitenum_macro(
     item: proc_macro2::TokenStream,
     enumtrait_model_bytes: &[u8]
+) -> Result<TraitEnumMacroOutput, syn::Error> {
+    parse_traitenum_macro_for_trait(item, enumtrait_model_bytes, None, None)
+}
+
+pub(crate) fn parse_traitenum_macro_for_trait(
+    item: proc_macro2::TokenStream,
+    enumtrait_model_bytes: &[u8],
+    trait_path: Option<&syn::Path>,
+    traitlib_path: Option<&syn::Path>
 ) -> Result<TraitEnumMacroOutput, syn::Error> {
     let enumtrait = model::EnumTrait::deserialize(enumtrait_model_bytes).unwrap();
     let input: syn::DeriveInput = syn::parse2(item)?;
-    let trait_ident = syn::Ident::new(enumtrait.identifier().name(), span(&input));
+    let trait_ident = match trait_path {
+        Some(path) => path.to_token_stream(),
+        None => syn::Ident::new(enumtrait.identifier().name(), span(&input)).to_token_stream()
+    };
 
     // the actual parsing is done with this call, the rest is building a tokenstream
     let traitenum = parse_traitenum_model(&input, &enumtrait)?;
@@ -41,12 +72,13 @@ pub(crate) fn parse_traitenum_macro(
 
         match method.attribute_definition() {
             model::Definition::Relation(reldef) => {
-                let rel_id = traitenum.relation_enum_identifier(method_name).unwrap();
-                let relation_path: syn::Path = rel_id.into();
                 let dispatch = reldef.dispatch().unwrap();
                 
                 match reldef.nature.unwrap() {
                     model::RelationNature::OneToMany => {
+                        let rel_id = traitenum.relation_enum_identifier(method_name).unwrap();
+                        let relation_path: syn::Path = rel_id.into();
+
                         match dispatch { 
                             model::Dispatch::BoxedTrait => {
                                 let iterator_ident = syn::Ident::new(
@@ -58,17 +90,39 @@ pub(crate) fn parse_traitenum_macro(
                                     }
                                 }
                             },
-                            model::Dispatch::Other => unimplemented!("Dispatch::Other is permanently unimplemented")
+                            // every derived enum provides its variants as a const slice
+                            model::Dispatch::StaticSlice => return quote::quote!{
+                                fn #func(&self) -> #return_type {
+                                    #relation_path::VARIANTS
+                                }
+                            },
                         }
                     },
                     model::RelationNature::ManyToOne | model::RelationNature::OneToOne => {
                         match dispatch { 
-                            model::Dispatch::BoxedTrait => return quote::quote!{
-                                fn #func(&self) -> #return_type {
-                                    ::std::boxed::Box::new(#relation_path)
+                            // each variant may override the enum-level target
+                            model::Dispatch::BoxedTrait => {
+                                let variant_outputs = data_enum.variants.iter().map(|variant_data| {
+                                    let variant_ident = &variant_data.ident;
+                                    let relation_path = traitenum
+                                        .variant(&variant_ident.to_string()).unwrap()
+                                        .value(method_name).unwrap()
+                                        .to_token_stream();
+
+                                    quote::quote!{
+                                        Self::#variant_ident => ::std::boxed::Box::new(#relation_path),
+                                    }
+                                });
+
+                                return quote::quote!{
+                                    fn #func(&self) -> #return_type {
+                                        match self {
+                                            #(#variant_outputs)*
+                                        }
+                                    }
                                 }
                             },
-                            model::Dispatch::Other => unimplemented!("Dispatch::Other is permanently unimplemented")
+                            model::Dispatch::StaticSlice => unreachable!("Static slice relations must be OneToMany"),
                         }
                     }
                 }
@@ -102,15 +156,43 @@ pub(crate) fn parse_traitenum_macro(
         output
     });
 
-    let boxed_trait_relation_iterators_outputs = build_boxed_trait_relation_iterators(&enumtrait, &traitenum)?;
+    let boxed_trait_relation_iterators_outputs = build_boxed_trait_relation_iterators(&enumtrait, &traitenum, &trait_ident)?;
 
     let input_ident = &input.ident;
+    let variants_output = build_variants_accessors(&enumtrait, &traitenum, data_enum);
+    let typed_relation_outputs = build_typed_relation_methods(&enumtrait, &traitenum);
+    let display_output = enumtrait.display_from().map(|method_name| {
+        let func = syn::Ident::new(method_name, span_site());
+        quote::quote!{
+            impl ::std::fmt::Display for #input_ident {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_str(<Self as #trait_ident>::#func(self))
+                }
+            }
+        }
+    });
+
+    let try_from_output = build_try_from(&input, &traitenum)?;
+
+    let enumtrait_model_output = build_enumtrait_model_accessor(&input, &enumtrait, &traitenum, traitlib_path)?;
 
     let output = quote::quote!{
         impl #trait_ident for #input_ident {
             #(#method_outputs)*
         }
 
+        impl #input_ident {
+            #variants_output
+
+            #(#typed_relation_outputs)*
+
+            #enumtrait_model_output
+        }
+
+        #display_output
+
+        #try_from_output
+
         #(#boxed_trait_relation_iterators_outputs)*
     };
 
@@ -132,7 +214,13 @@ fn parse_traitenum_model(input: &syn::DeriveInput, enumtrait: &model::EnumTrait)
     let mut traitenum_build = model::TraitEnumBuilder::new();
     traitenum_build.identifier(model::Identifier::from(&input.ident));
 
-    //parse top-level attributes (item.attr) as relations -> #[traitenum(<relation name>(<trait path>))]
+    // parse top-level attributes (item.attr) as either:
+    //   relations -> #[traitenum(<relation name>(<trait path>))]
+    //   a TryFrom<&str> implementation -> #[traitenum(try_from = "<method name>")]
+    //   an enumtrait_model() accessor -> #[traitenum(enumtrait_model)]
+    //   a VARIANTS const and variants() iterator -> #[traitenum(variants)]
+    //   enum-wide defaults for all other attributes -> #[traitenum(<attribute name>(<value>))]
+    let mut enum_defaults: HashMap<String, model::AttributeValue> = HashMap::new();
     for attr in &input.attrs {
         attr.parse_nested_meta(|meta| {
             // this will be the method and relation name as well
@@ -142,27 +230,54 @@ fn parse_traitenum_model(input: &syn::DeriveInput, enumtrait: &model::EnumTrait)
                 })?
                 .to_string();
 
+            if attr_name == ARG_ENUMTRAIT_MODEL {
+                traitenum_build.enumtrait_model_accessor(true);
+                return Ok(());
+            } else if attr_name == ARG_VARIANTS {
+                traitenum_build.variants_accessor(true);
+                return Ok(());
+            } else if attr_name == ARG_TRY_FROM {
+                if traitenum_build.has_try_from_method() {
+                    synerr!(attr, "Duplicate traitenum attribute for enum: {}", attr_name);
+                }
+
+                let method_lit: syn::LitStr = meta.value()?.parse()?;
+                let method_name = method_lit.value();
+                match enumtrait.method(&method_name) {
+                    Some(method) if method.return_type() == model::ReturnType::StaticStr => {},
+                    Some(_) => synerr!(&method_lit, "Method for `{}` must return `{}`: {}",
+                        ARG_TRY_FROM, model::ReturnType::STATIC_STR, method_name),
+                    None => synerr!(&method_lit, "Unknown method for `{}`: {}", ARG_TRY_FROM, method_name)
+                }
+
+                traitenum_build.try_from_method(method_name);
+                return Ok(());
+            }
+
             // prevent duplicates
-            if traitenum_build.has_relation_enum(&attr_name) {
+            if traitenum_build.has_relation_enum(&attr_name) || enum_defaults.contains_key(&attr_name) {
                 synerr!(attr, "Duplicate traitenum attribute for enum: {}", attr_name);
             }
 
             // find the matching trait method by name
-            let attribute_definition = enumtrait.methods().iter()
-                .find(|m| { m.name() == attr_name })
+            let attribute_definition = enumtrait.method(&attr_name)
                 .ok_or_else(|| mksynerr!(attr, "No matching trait method for enum attribute: {}", attr_name))?
                 .attribute_definition();
 
-            // ensure that we're using a relation attribute definition for this method
-            match attribute_definition {
-                model::Definition::Relation(_) => (),
-                _ => synerr!(attr, "Trait method definition is not a Relation as expected for enum attribute: {}", attr_name)
-            }
-
             let content;
             syn::parenthesized!(content in meta.input);
-            let relation_path: syn::Path = content.parse()?;
-            traitenum_build.relation_enum(attr_name, relation_path.try_into().unwrap());
+
+            match attribute_definition {
+                model::Definition::Relation(_) => {
+                    let relation_path: syn::Path = content.parse()?;
+                    traitenum_build.relation_enum(attr_name, relation_path.try_into().unwrap());
+                },
+                _ => {
+                    // applied to each variant that doesn't provide its own value
+                    let value = parse::parse_value(&attr_name, attribute_definition, &content)?;
+                    enum_defaults.insert(attr_name, model::AttributeValue::new(value));
+                }
+            }
 
             Ok(())
         })?;
@@ -171,9 +286,19 @@ fn parse_traitenum_model(input: &syn::DeriveInput, enumtrait: &model::EnumTrait)
 
     // parse enum attribute values, if provided
     let data_enum = data_enum(input)?;
+    // an uninhabited enum would generate empty match bodies and dead relation iterators
+    if data_enum.variants.is_empty() {
+        synerr!(input, "traitenum requires at least one variant");
+    }
+
     let mut ordinal: usize = 0;
     for variant in &data_enum.variants {
         let variant_name = variant.ident.to_string();
+        // generated methods match on `Self::#variant_ident`, which only works for unit variants
+        if !matches!(variant.fields, syn::Fields::Unit) {
+            synerr!(variant, "traitenum supports only fieldless enums; variant `{}` has fields", variant_name);
+        }
+
         // find the #[traitenum] attribute or continue
         let attribute = variant.attrs.iter()
             .find(|a| a.path().segments.first()
@@ -187,6 +312,8 @@ fn parse_traitenum_model(input: &syn::DeriveInput, enumtrait: &model::EnumTrait)
             build
         };
 
+        variant_build.ordinal(ordinal);
+
         // set attribute value defaults. throw errors where values are required, but not provided
         for method in enumtrait.methods() {
             let method_name = method.name();
@@ -195,10 +322,14 @@ fn parse_traitenum_model(input: &syn::DeriveInput, enumtrait: &model::EnumTrait)
                 continue;
             } else if !definition.needs_value() {
                 continue;
+            } else if let Some(enum_default) = enum_defaults.get(method_name) {
+                variant_build.value(method_name.to_string(), enum_default.clone());
             } else if !definition.has_default_or_preset() {
                 synerr!(variant, "Missing value for attribute `{}`: {}", method_name, variant_name);
             } else {
-                let value = definition.default_or_preset(&variant_name, ordinal).unwrap();
+                let value = definition.default_or_preset(&variant_name, ordinal)
+                    .ok_or_else(|| mksynerr!(variant, "Preset value for attribute `{}` is out of range for `{}`: {}",
+                        method_name, method.return_type(), variant_name))?;
                 variant_build.value(method_name.to_string(), model::AttributeValue::new(value));
             }
         }
@@ -218,7 +349,21 @@ fn parse_traitenum_model(input: &syn::DeriveInput, enumtrait: &model::EnumTrait)
                                 unreachable!();
                             }
                         },
-                        Some(_) => (),
+                        // a variant's own target overrides the one declared at the top of the enum
+                        Some(_) => {
+                            let method_name = method.name();
+                            if variant_build.has_value(method_name) {
+                                continue;
+                            }
+
+                            let relation_id = traitenum_build.get_relation_enum(method_name)
+                                .ok_or_else(|| mksynerr!(variant, "Missing value for relation `{}`: {}",
+                                    method_name, variant_name))?
+                                .to_owned();
+
+                            variant_build.value(method_name.to_owned(),
+                                model::AttributeValue::new(model::Value::Relation(relation_id)));
+                        },
                         None => unreachable!(),
                     }
                 },
@@ -233,12 +378,186 @@ fn parse_traitenum_model(input: &syn::DeriveInput, enumtrait: &model::EnumTrait)
     Ok(traitenum_build.build())
 }
 
+const ARG_TRY_FROM: &'static str = "try_from";
+const ARG_ENUMTRAIT_MODEL: &'static str = "enumtrait_model";
+const ARG_VARIANTS: &'static str = "variants";
+
+// Lists the enum's variants, both as a const slice and by value. Only generated when opted in, or when the enum
+// may be the target of a one-to-many relation (i.e., it relates many-to-one back), whose static slice and typed
+// companion are built from them. This avoids colliding with an enum's own `VARIANTS` or `variants()`.
+fn build_variants_accessors(
+    enumtrait: &model::EnumTrait,
+    traitenum: &model::TraitEnum,
+    data_enum: &syn::DataEnum
+) -> Option<proc_macro2::TokenStream> {
+    let is_relation_target = enumtrait.relation_methods().iter()
+        .any(|(_, rel)| rel.dispatch().unwrap() == model::Dispatch::BoxedTrait
+            && rel.nature().unwrap() == model::RelationNature::ManyToOne);
+
+    if !traitenum.variants_accessor() && !is_relation_target {
+        return None;
+    }
+
+    let variant_idents = data_enum.variants.iter().map(|variant| &variant.ident);
+    let variant_ordinal_match_body = data_enum.variants.iter().enumerate().map(|(ordinal, variant)| {
+        let variant_ident = &variant.ident;
+        quote::quote!{ #ordinal => Self::#variant_ident, }
+    });
+    let variant_count = data_enum.variants.len();
+
+    Some(quote::quote!{
+        /// All variants of this enum, in order.
+        pub const VARIANTS: &'static [Self] = &[#(Self::#variant_idents),*];
+
+        /// All variants of this enum, in order, by value.
+        pub fn variants() -> impl ::std::iter::Iterator<Item = Self> {
+            (0..#variant_count).map(|ordinal| match ordinal {
+                #(#variant_ordinal_match_body)*
+                _ => unreachable!()
+            })
+        }
+    })
+}
+
+// Reflects upon the model at runtime, deserializing the lib crate's model bytes only once. Opt-in, as the
+// accessor requires the deriving crate to depend on traitenum-lib.
+fn build_enumtrait_model_accessor(
+    input: &syn::DeriveInput,
+    enumtrait: &model::EnumTrait,
+    traitenum: &model::TraitEnum,
+    traitlib_path: Option<&syn::Path>
+) -> Result<Option<proc_macro2::TokenStream>, syn::Error> {
+    if !traitenum.enumtrait_model_accessor() {
+        return Ok(None);
+    }
+
+    let traitlib_path = traitlib_path
+        .ok_or_else(|| mksynerr!(input, "The `{}` attribute requires a derive crate that calls `gen_require!()`",
+            ARG_ENUMTRAIT_MODEL))?;
+    let model_bytes_ident = syn::Ident::new(
+        &format!("{}{}", super::MODEL_BYTES_NAME, enumtrait.identifier().name().to_case(case::Case::ScreamingSnake)),
+        span_site());
+
+    Ok(Some(quote::quote!{
+        /// The model of the enumtrait that this enum implements.
+        pub fn enumtrait_model() -> &'static ::traitenum_lib::model::EnumTrait {
+            static MODEL: ::std::sync::OnceLock<::traitenum_lib::model::EnumTrait> = ::std::sync::OnceLock::new();
+            MODEL.get_or_init(|| {
+                ::traitenum_lib::model::EnumTrait::deserialize(#traitlib_path::#model_bytes_ident)
+                    .expect("Unable to deserialize enumtrait model")
+            })
+        }
+    }))
+}
+
+const SUFFIX_TRY_FROM_ERROR: &'static str = "TryFromError";
+
+// Matches each variant by its value for the `try_from` method, listing all of them when none match.
+// The error type is generated alongside the enum (e.g., MyEnumTryFromError), so that no dependency is required.
+fn build_try_from(input: &syn::DeriveInput, traitenum: &model::TraitEnum)
+        -> Result<Option<proc_macro2::TokenStream>, syn::Error> {
+    let method_name = match traitenum.try_from_method() { Some(m) => m, None => return Ok(None) };
+
+    let mut names: Vec<&str> = Vec::new();
+    let mut variant_idents: Vec<syn::Ident> = Vec::new();
+    for variant in traitenum.variants() {
+        let name = match variant.value(method_name).map(|v| v.value()) {
+            Some(model::Value::StaticStr(name)) => name.as_str(),
+            _ => unreachable!("Missing `&'static str` value for `{}`: {}", method_name, variant.name())
+        };
+
+        if names.contains(&name) {
+            synerr!(input, "Duplicate value for `{}` method `{}`: {}", ARG_TRY_FROM, method_name, variant.name());
+        }
+
+        names.push(name);
+        variant_idents.push(syn::Ident::new(variant.name(), span_site()));
+    }
+
+    let input_ident = &input.ident;
+    let input_vis = &input.vis;
+    let error_ident = syn::Ident::new(&format!("{}{}", input_ident, SUFFIX_TRY_FROM_ERROR), input_ident.span());
+    let error_doc = format!("Returned by `{}::try_from()` when no variant matches, listing the names that would have.",
+        input_ident);
+
+    Ok(Some(quote::quote!{
+        #[doc = #error_doc]
+        #[derive(Debug, PartialEq)]
+        #input_vis struct #error_ident {
+            pub name: ::std::string::String,
+            pub valid_names: &'static [&'static str]
+        }
+
+        impl ::std::fmt::Display for #error_ident {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "Unknown variant name `{}`. Expected one of: {}", self.name, self.valid_names.join(", "))
+            }
+        }
+
+        impl ::std::error::Error for #error_ident {}
+
+        impl ::std::convert::TryFrom<&str> for #input_ident {
+            type Error = #error_ident;
+
+            fn try_from(name: &str) -> ::std::result::Result<Self, Self::Error> {
+                match name {
+                    #(#names => Ok(Self::#variant_idents),)*
+                    _ => Err(#error_ident {
+                        name: name.to_owned(),
+                        valid_names: &[#(#names),*]
+                    })
+                }
+            }
+        }
+    }))
+}
+
 const IDENT_BOXED_ITERATOR: &'static str = "BoxedIterator";
+const SUFFIX_TYPED: &'static str = "_typed";
+
+// Creates a companion for each dynamically dispatched one-to-many relation, iterating the concrete related enum.
+// E.g., children() -> children_typed()
+// Only generated when every variant relates to the same enum, as there is otherwise no single concrete item type.
+fn build_typed_relation_methods(
+    enumtrait: &model::EnumTrait,
+    traitenum: &model::TraitEnum) -> Vec<proc_macro2::TokenStream>
+{
+    enumtrait.relation_methods().iter()
+        .filter(|(_, rel)| rel.dispatch().unwrap() == model::Dispatch::BoxedTrait)
+        .filter(|(_, rel)| rel.nature().unwrap() == model::RelationNature::OneToMany)
+        .filter_map(|(method, _relation_def)| {
+            let mut relation_ids = traitenum.variants().iter()
+                .map(|variant| match variant.value(method.name()).map(|v| v.value()) {
+                    Some(model::Value::Relation(id)) => id,
+                    _ => unreachable!("Missing relation value for `{}`: {}", method.name(), variant.name())
+                });
+
+            let relation_id = relation_ids.next()?;
+            if relation_ids.any(|id| id != relation_id) {
+                return None;
+            }
+
+            Some((method, relation_id))
+        })
+        .map(|(method, relation_id)| {
+            let func = syn::Ident::new(&format!("{}{}", method.name(), SUFFIX_TYPED), span_site());
+            let relation_path: syn::Path = relation_id.into();
+
+            quote::quote!{
+                /// Iterates the concrete variants of the related enum, rather than boxed trait objects.
+                pub fn #func(&self) -> impl ::std::iter::Iterator<Item = #relation_path> {
+                    #relation_path::variants()
+                }
+            }
+        })
+        .collect()
+}
 
 // Creates iterator structs and implementations for dynamically dispatched many-to-many relations
 fn build_boxed_trait_relation_iterators(
     enumtrait: &model::EnumTrait,
-    traitenum: &model::TraitEnum) -> syn::Result<Vec<proc_macro2::TokenStream>>
+    traitenum: &model::TraitEnum,
+    trait_ident: &proc_macro2::TokenStream) -> syn::Result<Vec<proc_macro2::TokenStream>>
 {
     let structs = enumtrait.relation_methods().iter()
         .filter(|(_, rel)| rel.dispatch().unwrap() == model::Dispatch::BoxedTrait)
@@ -249,18 +568,14 @@ fn build_boxed_trait_relation_iterators(
                 &format!("{}{}", traitenum.identifier().name(), IDENT_BOXED_ITERATOR), span_site());
                 
             let item_path: syn::Path = traitenum.identifier().try_into().unwrap();
-            let item_trait_path: syn::Path = enumtrait.identifier().try_into().unwrap();
 
             // Build the match body for the Iterator's next(). This simply maps a traitenum variant by its ordinal.
-            let mut ordinal: usize = 0;
             let next_ordinal_match_body = traitenum.variants().iter().map(|variant| {
                 let variant_ident = syn::Ident::new(variant.name(), span_site());
-                let output = quote::quote!{
+                let ordinal = variant.ordinal();
+                quote::quote!{
                     #ordinal => ::std::option::Option::Some(Box::new(#item_path::#variant_ident)),
-                };
-
-                ordinal += 1;
-                output
+                }
             });
 
             // Build the Iterator struct, it's new function, and it's Iterator implementation for the traitenum.
@@ -278,7 +593,7 @@ fn build_boxed_trait_relation_iterators(
                 }
 
                 impl ::std::iter::Iterator for #iterator_ident {
-                    type Item = ::std::boxed::Box<dyn #item_trait_path>;
+                    type Item = ::std::boxed::Box<dyn #trait_ident>;
 
                     fn next(&mut self) -> std::option::Option<Self::Item> {
                         let ordinal = self.next_ordinal;
@@ -9,11 +9,18 @@ pub mod parse;
 pub struct EnumTrait {
     identifier: Identifier,
     methods: Vec<Method>,
+    display_from: Option<String>,
 }
 
 impl EnumTrait {
     pub fn identifier(&self) -> &Identifier { &self.identifier }
     pub fn methods(&self) -> &[Method] { &self.methods }
+    /// The name of the string method that each enum's `Display` implementation delegates to, if any.
+    pub fn display_from(&self) -> Option<&str> { self.display_from.as_deref() }
+
+    pub fn method(&self, name: &str) -> Option<&Method> {
+        self.methods.iter().find(|m| name == m.name)
+    }
     
     pub fn relation_methods(&self) -> Vec<(&Method, &RelationDefinition)> {
         self.methods.iter()
@@ -26,10 +33,126 @@ impl EnumTrait {
             .collect()
     }
 
+    /// The identifier of the trait that a relation method refers to, if `method_name` is a relation.
+    pub fn relation_target_trait(&self, method_name: &str) -> Option<&Identifier> {
+        self.method(method_name)
+            .and_then(|method| match method.attribute_definition {
+                Definition::Relation(ref relation_def) => Some(relation_def.identifier()),
+                _ => None
+            })
+    }
+
     pub const fn new(identifier: Identifier, methods: Vec<Method>) -> Self {
         Self {
             identifier,
             methods,
+            display_from: None,
+        }
+    }
+
+    pub fn with_display_from(mut self, method_name: String) -> Self {
+        self.display_from = Some(method_name);
+        self
+    }
+
+    /// Lists the method changes required to turn this model into `other`. Removals and return type changes are breaking.
+    pub fn diff(&self, other: &EnumTrait) -> Vec<ModelChange> {
+        let mut changes = Vec::new();
+
+        for method in &self.methods {
+            match other.method(&method.name) {
+                None => changes.push(ModelChange::MethodRemoved(method.name.to_owned())),
+                Some(other_method) if method.return_type != other_method.return_type => {
+                    changes.push(ModelChange::ReturnTypeChanged {
+                        method: method.name.to_owned(),
+                        from: method.return_type,
+                        to: other_method.return_type
+                    })
+                },
+                Some(other_method) if method.attribute_definition != other_method.attribute_definition => {
+                    changes.push(ModelChange::DefinitionChanged(method.name.to_owned()))
+                },
+                Some(_) => {}
+            }
+        }
+
+        other.methods.iter()
+            .filter(|other_method| self.method(&other_method.name).is_none())
+            .for_each(|other_method| changes.push(ModelChange::MethodAdded(other_method.name.to_owned())));
+
+        changes
+    }
+
+    /// Validates a deserialized [TraitEnum] against this model at runtime, as the derive macro would have during
+    /// expansion. Each variant must provide a value for every method that needs one, unless it can be defaulted or
+    /// preset, and each relation must have a related enum. Lists every problem found.
+    pub fn validate_traitenum(&self, traitenum: &TraitEnum) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        for method in &self.methods {
+            let method_name = method.name();
+            match method.attribute_definition {
+                Definition::Relation(ref reldef) => {
+                    let has_relation_enum = traitenum.relation_enum_identifier(method_name).is_some();
+                    match reldef.nature {
+                        Some(RelationNature::ManyToOne) | Some(RelationNature::OneToOne) => {
+                            // each variant may provide its own target in lieu of the enum-level one
+                            traitenum.variants().iter()
+                                .filter(|variant| !has_relation_enum && !variant.has_value(method_name))
+                                .for_each(|variant| errors.push(format!(
+                                    "Missing value for relation `{}`: {}", method_name, variant.name())));
+                        },
+                        Some(RelationNature::OneToMany) | None => {
+                            if !has_relation_enum {
+                                errors.push(format!("Missing relation enum for relation: {}", method_name));
+                            }
+                        }
+                    }
+                },
+                ref definition => {
+                    traitenum.variants().iter()
+                        .filter(|variant| definition.needs_value() && !variant.has_value(method_name))
+                        .filter(|_| !definition.has_default_or_preset())
+                        .for_each(|variant| errors.push(format!(
+                            "Missing value for attribute `{}`: {}", method_name, variant.name())));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// A single difference between two versions of an [EnumTrait] model, as produced by [EnumTrait::diff].
+#[derive(Debug, PartialEq)]
+pub enum ModelChange {
+    MethodAdded(String),
+    MethodRemoved(String),
+    ReturnTypeChanged { method: String, from: ReturnType, to: ReturnType },
+    DefinitionChanged(String),
+}
+
+impl ModelChange {
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            ModelChange::MethodRemoved(_) | ModelChange::ReturnTypeChanged { .. } => true,
+            ModelChange::MethodAdded(_) | ModelChange::DefinitionChanged(_) => false
+        }
+    }
+}
+
+impl Display for ModelChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModelChange::MethodAdded(name) => write!(f, "Added method `{}`", name),
+            ModelChange::MethodRemoved(name) => write!(f, "Removed method `{}`", name),
+            ModelChange::ReturnTypeChanged { method, from, to } =>
+                write!(f, "Changed return type of method `{}` from `{}` to `{}`", method, from, to),
+            ModelChange::DefinitionChanged(name) => write!(f, "Changed attribute definition of method `{}`", name),
         }
     }
 }
@@ -90,7 +213,7 @@ pub enum ReturnType {
     Byte,
     BoxedTrait,
     BoxedTraitIterator,
-    AssociatedType,
+    StaticSlice,
     Enum,
     Type
 }
@@ -124,7 +247,7 @@ impl Display for ReturnType {
             // complex types
             ReturnType::BoxedTrait => write!(f, "Box<dyn Trait>"),
             ReturnType::BoxedTraitIterator => write!(f, "Box<dyn Iterator<Item = Box<dyn Trait>>>"),
-            ReturnType::AssociatedType => write!(f, "<Self::Type>"),
+            ReturnType::StaticSlice => write!(f, "&'static [Enum]"),
             ReturnType::Enum => write!(f, "<Enum>"),
             ReturnType::Type => write!(f, "<Type>"),
         }
@@ -233,6 +356,8 @@ impl Definition {
                         let mut attr_def = Definition::Relation(RelationDefinition::new(id));
                         let rel_def = attr_def.get_relation_definition_mut();
                         rel_def.dispatch = Some(Dispatch::BoxedTrait);
+                        // an elided nature is inferred as many-to-one. OneToOne must be explicitly opted into.
+                        rel_def.nature = Some(RelationNature::ManyToOne);
                         attr_def
                     },
                     Some(s) => {
@@ -260,13 +385,14 @@ impl Definition {
                     } 
                 }
             },
-            //TODO: remove
-            ReturnType::AssociatedType => {
+            ReturnType::StaticSlice => {
                 chk_defname!(RelationDefinition::TYPE_NAME);
-                let id = return_identifier.ok_or("Missing Identifier for ReturnType::AssociatedType")?;
-                let mut reldef = RelationDefinition::new(id);
-                reldef.dispatch = Some(Dispatch::Other);
-                Definition::Relation(reldef)
+                let id = return_identifier.ok_or("Missing Identifier for ReturnType::StaticSlice")?;
+                let mut attr_def = Definition::Relation(RelationDefinition::new(id));
+                let rel_def = attr_def.get_relation_definition_mut();
+                rel_def.dispatch = Some(Dispatch::StaticSlice);
+                rel_def.nature = Some(RelationNature::OneToMany);
+                attr_def
             },
             // Enums will never be implied as their type cannot be determined without reflection
             // This is here for posterity
@@ -395,12 +521,21 @@ impl Definition {
                {
                     let preset = match &$numdef.preset { Some(p) => p, None => return None };
                     match preset {
-                        NumberPreset::Ordinal => Some($value_variant(ordinal as $num_type)),
+                        // overflow yields None, which is reported against the variant
+                        NumberPreset::Ordinal => <$num_type as Number>::ordinal(ordinal).map($value_variant),
                         NumberPreset::Serial => {
                             let start = match $numdef.start { Some(n) => n, None => return None };
                             let increment = match $numdef.increment { Some(n) => n, None => return None };
-                            let val = start + (ordinal as $num_type * increment);
-                            Some($value_variant(val))
+                            // overflow yields None, which is reported against the variant
+                            <$num_type as Number>::serial(start, increment, ordinal, $numdef.descending)
+                                .and_then(|n| match $numdef.modulo { Some(modulo) => n.wrap(modulo), None => Some(n) })
+                                .map($value_variant)
+                        },
+                        NumberPreset::Geometric => {
+                            let start = match $numdef.start { Some(n) => n, None => return None };
+                            let factor = match $numdef.factor { Some(n) => n, None => return None };
+                            // overflow yields None, which is reported against the variant
+                            <$num_type as Number>::geometric(start, factor, ordinal).map($value_variant)
                         }
                     } 
                 }
@@ -411,7 +546,7 @@ impl Definition {
             Definition::Bool(_booldef) => None,
             Definition::StaticStr(ref strdef) => {
                 let preset = match &strdef.preset { Some(p) => p, None => return None };
-                Some(Value::StaticStr(preset.convert(variant_name)))
+                Some(Value::StaticStr(strdef.affix(preset.convert(variant_name))))
             },
             Definition::UnsignedSize(ref numdef) => preset_numdef!(Value::UnsignedSize, usize, numdef),
             Definition::UnsignedInteger64(ref numdef) => preset_numdef!(Value::UnsignedInteger64, u64, numdef),
@@ -461,7 +596,7 @@ impl Definition {
 
         match self {
             Definition::Bool(_booldef) => Ok(()),
-            Definition::StaticStr(_strdef) => Ok(()),
+            Definition::StaticStr(strdef) => strdef.validate(),
             Definition::UnsignedSize(numdef) => numdef.validate(),
             Definition::UnsignedInteger64(numdef) => numdef.validate(),
             Definition::Integer64(numdef) => numdef.validate(),
@@ -510,15 +645,18 @@ impl BoolDefinition {
     }
 }
 
-#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct NumberDefinition<N> {
     pub(crate) default: Option<N>,
     pub(crate) preset: Option<NumberPreset>,
     pub(crate) start: Option<N>,
     pub(crate) increment: Option<N>,
+    pub(crate) factor: Option<N>,
+    pub(crate) descending: bool,
+    pub(crate) modulo: Option<N>,
 }
 
-impl<N> NumberDefinition<N> {
+impl<N: Number> NumberDefinition<N> {
     const DEFINITION_NAME: &'static str = "Num";
 
     pub fn new() -> Self {
@@ -526,11 +664,26 @@ impl<N> NumberDefinition<N> {
             default: None,
             preset: None,
             start: None,
-            increment: None
+            increment: None,
+            factor: None,
+            descending: false,
+            modulo: None
         }
     }
     
     pub fn validate(&self) -> Result<(), &str> {
+        if self.descending && self.preset != Some(NumberPreset::Serial) {
+            return Err("The `descending` setting requires the `Serial` number preset");
+        }
+
+        if let Some(modulo) = &self.modulo {
+            if self.preset != Some(NumberPreset::Serial) {
+                return Err("The `modulo` setting requires the `Serial` number preset");
+            } else if modulo.is_zero() {
+                return Err("The `modulo` setting must be nonzero");
+            }
+        }
+
         let preset = match &self.preset { Some(p) => p, None => return Ok(()) };
         match preset {
             NumberPreset::Ordinal => Ok(()),
@@ -542,13 +695,152 @@ impl<N> NumberDefinition<N> {
                 } else {
                     Ok(())
                 }
+            },
+            NumberPreset::Geometric => {
+                if self.start.is_none() {
+                    Err("Missing attribute for `Geometric` number preset: start")
+                } else if self.factor.is_none() {
+                    Err("Missing attribute for `Geometric` number preset: factor")
+                } else {
+                    Ok(())
+                }
             }
         }
     }
 }
 
+// bitwise float equality keeps models with NaN settings equal to themselves
+impl<N: Number> PartialEq for NumberDefinition<N> {
+    fn eq(&self, other: &Self) -> bool {
+        let eq = |a: &Option<N>, b: &Option<N>| match (a, b) {
+            (Some(a), Some(b)) => a.bits_eq(b),
+            (None, None) => true,
+            _ => false
+        };
+
+        eq(&self.default, &other.default)
+            && self.preset == other.preset
+            && eq(&self.start, &other.start)
+            && eq(&self.increment, &other.increment)
+            && eq(&self.factor, &other.factor)
+            && self.descending == other.descending
+            && eq(&self.modulo, &other.modulo)
+    }
+}
+
+/// The primitive types that a [NumberDefinition] may model, along with their bounds.
+pub trait Number: Copy + FromStr + Display {
+    const MIN: Self;
+    const MAX: Self;
+
+    /// Converts an ordinal, returning None if it is out of range.
+    fn ordinal(ordinal: usize) -> Option<Self>;
+
+    /// Computes `start + (ordinal * increment)`, or `start - (ordinal * increment)` if descending, returning None if
+    /// the result is out of range.
+    fn serial(start: Self, increment: Self, ordinal: usize, descending: bool) -> Option<Self>;
+
+    /// Computes `start * factor^exponent`, returning None if the result is out of range.
+    fn geometric(start: Self, factor: Self, exponent: usize) -> Option<Self>;
+
+    /// Wraps into the range `0..modulo` (Euclidean remainder), returning None if modulo is zero.
+    fn wrap(self, modulo: Self) -> Option<Self>;
+
+    fn is_zero(&self) -> bool;
+
+    /// Resolves a special value that has no literal form, by its path. E.g., `f64::NAN`
+    fn from_constant(path: &str) -> Option<Self>;
+
+    /// Equality that treats bitwise-equal floats, including NaN, as equal.
+    fn bits_eq(&self, other: &Self) -> bool;
+}
+
+macro_rules! impl_number {
+    (int: $($int_type:ident),+; float: $($float_type:ident),+) => {
+        $(
+            impl Number for $int_type {
+                const MIN: Self = $int_type::MIN;
+                const MAX: Self = $int_type::MAX;
+
+                fn ordinal(ordinal: usize) -> Option<Self> {
+                    Self::try_from(ordinal).ok()
+                }
+
+                fn serial(start: Self, increment: Self, ordinal: usize, descending: bool) -> Option<Self> {
+                    let offset = Self::try_from(ordinal).ok()?.checked_mul(increment)?;
+                    if descending { start.checked_sub(offset) } else { start.checked_add(offset) }
+                }
+
+                fn geometric(start: Self, factor: Self, exponent: usize) -> Option<Self> {
+                    factor.checked_pow(u32::try_from(exponent).ok()?)?.checked_mul(start)
+                }
+
+                fn wrap(self, modulo: Self) -> Option<Self> {
+                    self.checked_rem_euclid(modulo)
+                }
+
+                fn is_zero(&self) -> bool {
+                    *self == 0
+                }
+
+                fn from_constant(_path: &str) -> Option<Self> {
+                    None
+                }
+
+                fn bits_eq(&self, other: &Self) -> bool {
+                    self == other
+                }
+            }
+        )+
+        $(
+            impl Number for $float_type {
+                const MIN: Self = $float_type::MIN;
+                const MAX: Self = $float_type::MAX;
+
+                fn ordinal(ordinal: usize) -> Option<Self> {
+                    Some(ordinal as $float_type)
+                }
+
+                fn serial(start: Self, increment: Self, ordinal: usize, descending: bool) -> Option<Self> {
+                    let offset = ordinal as $float_type * increment;
+                    let n = if descending { start - offset } else { start + offset };
+                    if n.is_finite() { Some(n) } else { None }
+                }
+
+                fn geometric(start: Self, factor: Self, exponent: usize) -> Option<Self> {
+                    let n = start * factor.powi(i32::try_from(exponent).ok()?);
+                    if n.is_finite() { Some(n) } else { None }
+                }
+
+                fn wrap(self, modulo: Self) -> Option<Self> {
+                    if modulo.is_zero() { None } else { Some(self.rem_euclid(modulo)) }
+                }
+
+                fn is_zero(&self) -> bool {
+                    *self == 0.0
+                }
+
+                fn from_constant(path: &str) -> Option<Self> {
+                    match path.strip_prefix(concat!(stringify!($float_type), "::"))? {
+                        "NAN" => Some($float_type::NAN),
+                        "INFINITY" => Some($float_type::INFINITY),
+                        "NEG_INFINITY" => Some($float_type::NEG_INFINITY),
+                        _ => None
+                    }
+                }
+
+                fn bits_eq(&self, other: &Self) -> bool {
+                    self.to_bits() == other.to_bits()
+                }
+            }
+        )+
+    };
+}
+
+impl_number!(int: usize, u64, i64, u32, i32, u8; float: f64, f32);
+
 /// Presets use the variant name as input and output a case conversion using the [convert_case](https://docs.rs/convert_case/latest/convert_case/enum.Case.html)
-/// crate. The `Variant` preset does no conversion.
+/// crate. The `Variant` preset does no conversion. The `Prefixed` and `Suffixed` presets affix the unaltered variant name.
 #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum StringPreset {
     /// The unaltered variant name
@@ -574,7 +866,11 @@ pub enum StringPreset {
     /// "MYVARIANTNAME"
     UpperFlat,
     /// "My-Variable-Name"
-    Train
+    Train,
+    /// The variant name with a prefix: Prefixed("ROLE_") -> "ROLE_MyVariantName"
+    Prefixed(String),
+    /// The variant name with a suffix: Suffixed("_ROLE") -> "MyVariantName_ROLE"
+    Suffixed(String)
 }
 
 impl FromStr for StringPreset {
@@ -614,14 +910,21 @@ impl StringPreset {
             Self::Flat => text.to_case(case::Case::Flat),
             Self::UpperFlat => text.to_case(case::Case::UpperFlat),
             Self::Train => text.to_case(case::Case::Train),
+            Self::Prefixed(prefix) => format!("{prefix}{text}"),
+            Self::Suffixed(suffix) => format!("{text}{suffix}"),
         }
     }
 }
 
 #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum NumberPreset {
+    /// The variant's ordinal
     Ordinal,
+    /// `start + (ordinal * increment)`, or `start - (ordinal * increment)` if descending, optionally wrapped by a
+    /// `modulo`
     Serial,
+    /// `start * factor^ordinal`
+    Geometric,
 }
 
 impl FromStr for NumberPreset {
@@ -631,6 +934,7 @@ impl FromStr for NumberPreset {
         match variant_name {
             "Ordinal" => Ok(Self::Ordinal),
             "Serial" => Ok(Self::Serial),
+            "Geometric" => Ok(Self::Geometric),
             _ => Err(())
         }
     }
@@ -640,6 +944,8 @@ impl FromStr for NumberPreset {
 pub struct StaticStrDefinition {
     pub(crate) default: Option<String>,
     pub(crate) preset: Option<StringPreset>,
+    pub(crate) prefix: Option<String>,
+    pub(crate) suffix: Option<String>,
 }
 
 impl StaticStrDefinition {
@@ -648,8 +954,26 @@ impl StaticStrDefinition {
     pub fn new() -> Self {
         Self {
             default: None,
-            preset: None
+            preset: None,
+            prefix: None,
+            suffix: None
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), &str> {
+        if (self.prefix.is_some() || self.suffix.is_some()) && self.preset.is_none() {
+            return Err("A prefix or suffix requires a preset");
         }
+
+        Ok(())
+    }
+
+    /// Applies the prefix and suffix settings, if any, to a preset's output
+    fn affix(&self, text: String) -> String {
+        format!("{}{}{}",
+            self.prefix.as_deref().unwrap_or_default(),
+            text,
+            self.suffix.as_deref().unwrap_or_default())
     }
 }
 
@@ -700,7 +1024,7 @@ impl FromStr for RelationNature {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "OnetoOne" => Ok(Self::OneToOne),
+            "OneToOne" => Ok(Self::OneToOne),
             "OneToMany" => Ok(Self::OneToMany),
             "ManyToOne" => Ok(Self::ManyToOne),
             _ => Err(())
@@ -709,11 +1033,14 @@ impl FromStr for RelationNature {
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+/// Relations are dynamically dispatched, with the exception of one-to-many static slices of a concrete enum.
+/// Statically dispatched relations, via associated types, are not supported as each variant of an enum would need
+/// to return a distinct type.
 pub enum Dispatch {
     /// Box<dyn Trait> and Box<dyn Iterator<Item = Box<dyn Trait>>>
     BoxedTrait,
-    /// This is a placeholder to future-proof expansion
-    Other
+    /// &'static [Enum], for one-to-many relations. Implied by the return type rather than set.
+    StaticSlice,
 }
 
 impl FromStr for Dispatch {
@@ -722,7 +1049,6 @@ impl FromStr for Dispatch {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "BoxedTrait" => Ok(Self::BoxedTrait),
-            "Other" => Ok(Self::Other),
             _ => Err(())
         }
     }
@@ -755,8 +1081,12 @@ impl RelationDefinition {
     pub fn validate(&self) -> Result<(), &str> {
         match self.dispatch{
             Some(Dispatch::BoxedTrait) => {},
-            Some(Dispatch::Other) => return Err("Dispatch::Other is permanently unimplemented"),
-            None => return Err("Missing property for Rel definition: nature")
+            Some(Dispatch::StaticSlice) => {
+                if self.nature != Some(RelationNature::OneToMany) {
+                    return Err("Static slice relations must be OneToMany");
+                }
+            },
+            None => return Err("Missing property for Rel definition: dispatch")
         }
 
         match self.nature {
@@ -789,7 +1119,7 @@ impl Method {
     }
 }
 
-#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct AttributeValue {
     value: Value
 }
@@ -804,7 +1134,7 @@ impl AttributeValue {
     }
 }
 
-#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum Value {
     Bool(bool),
     StaticStr(String),
@@ -821,6 +1151,48 @@ pub enum Value {
     Type(Identifier),
 }
 
+// bitwise float equality keeps models with NaN values equal to themselves
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Bool(a), Value::Bool(b)) => a == b,
+            (Value::StaticStr(a), Value::StaticStr(b)) => a == b,
+            (Value::UnsignedInteger64(a), Value::UnsignedInteger64(b)) => a == b,
+            (Value::Integer64(a), Value::Integer64(b)) => a == b,
+            (Value::Float64(a), Value::Float64(b)) => a.bits_eq(b),
+            (Value::UnsignedInteger32(a), Value::UnsignedInteger32(b)) => a == b,
+            (Value::Integer32(a), Value::Integer32(b)) => a == b,
+            (Value::Float32(a), Value::Float32(b)) => a.bits_eq(b),
+            (Value::UnsignedSize(a), Value::UnsignedSize(b)) => a == b,
+            (Value::Byte(a), Value::Byte(b)) => a == b,
+            (Value::EnumVariant(a), Value::EnumVariant(b)) => a == b,
+            (Value::Relation(a), Value::Relation(b)) => a == b,
+            (Value::Type(a), Value::Type(b)) => a == b,
+            _ => false
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Bool(v) => write!(f, "{}", v),
+            Value::StaticStr(v) => write!(f, "{:?}", v),
+            Value::UnsignedInteger64(v) => write!(f, "{}", v),
+            Value::Integer64(v) => write!(f, "{}", v),
+            Value::Float64(v) => write!(f, "{}", v),
+            Value::UnsignedInteger32(v) => write!(f, "{}", v),
+            Value::Integer32(v) => write!(f, "{}", v),
+            Value::Float32(v) => write!(f, "{}", v),
+            Value::UnsignedSize(v) => write!(f, "{}", v),
+            Value::Byte(v) => write!(f, "{}", v),
+            Value::EnumVariant(id) => write!(f, "{}", id),
+            Value::Relation(id) => write!(f, "{}", id),
+            Value::Type(id) => write!(f, "{}", id),
+        }
+    }
+}
+
 impl EnumTrait {
     pub fn serialize(&self) -> bincode::Result<Vec<u8>>{
         bincode::serialize(self)
@@ -835,13 +1207,19 @@ impl EnumTrait {
 pub struct TraitEnum {
     identifier: Identifier,
     variants: Vec<Variant>,
-    named_relation_enum_ids: HashMap<String, Identifier>
+    named_relation_enum_ids: HashMap<String, Identifier>,
+    try_from_method: Option<String>,
+    enumtrait_model_accessor: bool,
+    variants_accessor: bool
 }
 
 pub(crate) struct TraitEnumBuilder {
     identifier: Option<Identifier>,
     variants: Option<Vec<Variant>>,
-    named_relation_enum_ids: Option<HashMap<String, Identifier>>
+    named_relation_enum_ids: Option<HashMap<String, Identifier>>,
+    try_from_method: Option<String>,
+    enumtrait_model_accessor: bool,
+    variants_accessor: bool
 }
 
 impl TraitEnumBuilder {
@@ -849,7 +1227,10 @@ impl TraitEnumBuilder {
         Self {
             identifier: None,
             variants: None,
-            named_relation_enum_ids: None
+            named_relation_enum_ids: None,
+            try_from_method: None,
+            enumtrait_model_accessor: false,
+            variants_accessor: false
         }
     }
 
@@ -876,6 +1257,11 @@ impl TraitEnumBuilder {
         }
     }
 
+    pub(crate) fn get_relation_enum(&self, relation_name: &str) -> Option<&Identifier> {
+        self.named_relation_enum_ids.as_ref()
+            .and_then(|named_relation_enum_ids| named_relation_enum_ids.get(relation_name))
+    }
+
     pub(crate) fn relation_enum(&mut self, relation_name: String, enum_identifier: Identifier) -> &mut Self {
         if let Some(named_relation_enum_ids) = &mut self.named_relation_enum_ids{
             named_relation_enum_ids.insert(relation_name, enum_identifier);
@@ -890,17 +1276,41 @@ impl TraitEnumBuilder {
         self
     }
 
+    pub(crate) fn try_from_method(&mut self, method_name: String) -> &mut Self {
+        self.try_from_method = Some(method_name);
+        self
+    }
+
+    pub(crate) fn enumtrait_model_accessor(&mut self, enabled: bool) -> &mut Self {
+        self.enumtrait_model_accessor = enabled;
+        self
+    }
+
+    pub(crate) fn variants_accessor(&mut self, enabled: bool) -> &mut Self {
+        self.variants_accessor = enabled;
+        self
+    }
+
+    pub(crate) fn has_try_from_method(&self) -> bool {
+        self.try_from_method.is_some()
+    }
+
     pub(crate) fn build(self) -> TraitEnum {
         let identifier = self.identifier
             .expect("Cannot build TraitEnum without an identifier");
         let variants = self.variants.unwrap_or_else(|| Vec::new() );
         let named_relation_enum_ids = self.named_relation_enum_ids.unwrap_or_else(|| HashMap::new() );
 
-        TraitEnum::new(
+        let mut traitenum = TraitEnum::new(
             identifier,
             variants,
             named_relation_enum_ids
-        )
+        );
+
+        traitenum.try_from_method = self.try_from_method;
+        traitenum.enumtrait_model_accessor = self.enumtrait_model_accessor;
+        traitenum.variants_accessor = self.variants_accessor;
+        traitenum
     }
 }
 
@@ -913,14 +1323,36 @@ impl TraitEnum {
         Self {
             identifier,
             variants,
-            named_relation_enum_ids: relation_enums
+            named_relation_enum_ids: relation_enums,
+            try_from_method: None,
+            enumtrait_model_accessor: false,
+            variants_accessor: false
         }
     }
 
+    /// The `&'static str` method whose values are matched by the derived `TryFrom<&str>`, if any
+    pub fn try_from_method(&self) -> Option<&str> {
+        self.try_from_method.as_deref()
+    }
+
+    /// Whether the derived enum opted in to an `enumtrait_model()` accessor
+    pub fn enumtrait_model_accessor(&self) -> bool {
+        self.enumtrait_model_accessor
+    }
+
+    /// Whether the derived enum opted in to its `VARIANTS` const and `variants()` iterator
+    pub fn variants_accessor(&self) -> bool {
+        self.variants_accessor
+    }
+
     pub fn variant(&self, name: &str) -> Option<&Variant> {
         self.variants.iter().find(|v| name == v.name )
     }
 
+    pub fn ordinal_of(&self, variant_name: &str) -> Option<usize> {
+        self.variant(variant_name).map(|v| v.ordinal)
+    }
+
     /// Each key matches a method name of the enumtrait, which has been modeled with a relation definition
     /// Each value is the Identifier for the related enum (also implementing enumtrait)
     pub fn relation_enums(&self) -> hash_map::Iter<'_, String, Identifier> {
@@ -935,19 +1367,23 @@ impl TraitEnum {
 #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Variant {
     name: String,
+    ordinal: usize,
     named_values: HashMap<String, AttributeValue>
 }
 
 impl Variant {
     pub fn name(&self) -> &str { &self.name }
+    /// The 0-based position of this variant within its enum's declaration
+    pub fn ordinal(&self) -> usize { self.ordinal }
 
     pub fn values(&self) -> hash_map::Iter<'_, String, AttributeValue>{
         self.named_values.iter()
     }
 
-    pub fn new(name: String, value_map: HashMap<String, AttributeValue>) -> Self {
+    pub fn new(name: String, ordinal: usize, value_map: HashMap<String, AttributeValue>) -> Self {
         Self {
             name,
+            ordinal,
             named_values: value_map
         }
     }
@@ -964,6 +1400,7 @@ impl Variant {
 
 pub(crate) struct VariantBuilder {
     name: Option<String>,
+    ordinal: Option<usize>,
     named_values: Option<HashMap<String, AttributeValue>>
 }
 
@@ -971,6 +1408,7 @@ impl VariantBuilder {
     pub(crate) fn new() -> Self {
         Self {
             name: None,
+            ordinal: None,
             named_values: None
         }
     }
@@ -980,6 +1418,11 @@ impl VariantBuilder {
         self
     }
 
+    pub(crate) fn ordinal(&mut self, ordinal: usize) -> &mut Self {
+        self.ordinal = Some(ordinal);
+        self
+    }
+
     pub(crate) fn has_value(&self, attribute_name: &str) -> bool {
         if let Some(named_values) = &self.named_values {
             named_values.contains_key(attribute_name)
@@ -1013,12 +1456,118 @@ impl VariantBuilder {
     pub(crate) fn build(self) -> Variant {
         let name = self.name
             .expect("Cannot build Variant without a name");
+        let ordinal = self.ordinal
+            .expect("Cannot build Variant without an ordinal");
         let named_values = self.named_values.unwrap_or_else(|| HashMap::new());
 
         Variant::new(
             name,
+            ordinal,
             named_values
         )
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enumtrait_method() {
+        let enumtrait = EnumTrait::new(
+            Identifier::new(vec![], "MyTrait".to_owned()),
+            vec![
+                Method::new("name".to_owned(), ReturnType::StaticStr, Definition::StaticStr(StaticStrDefinition::new())),
+                Method::new("column".to_owned(), ReturnType::UnsignedSize, Definition::UnsignedSize(NumberDefinition::new())),
+            ]);
+
+        assert_eq!("column", enumtrait.method("column").unwrap().name());
+        assert_eq!(ReturnType::StaticStr, enumtrait.method("name").unwrap().return_type());
+        assert!(enumtrait.method("missing").is_none());
+    }
+
+    #[test]
+    fn test_enumtrait_diff() {
+        let identifier = Identifier::new(vec![], "MyTrait".to_owned());
+        let before = EnumTrait::new(identifier.clone(), vec![
+            Method::new("name".to_owned(), ReturnType::StaticStr, Definition::StaticStr(StaticStrDefinition::new())),
+            Method::new("column".to_owned(), ReturnType::UnsignedSize, Definition::UnsignedSize(NumberDefinition::new())),
+        ]);
+        let after = EnumTrait::new(identifier, vec![
+            Method::new("name".to_owned(), ReturnType::StaticStr, Definition::StaticStr(StaticStrDefinition::new())),
+            Method::new("column".to_owned(), ReturnType::UnsignedInteger64, Definition::UnsignedInteger64(NumberDefinition::new())),
+            Method::new("enabled".to_owned(), ReturnType::Bool, Definition::Bool(BoolDefinition::new())),
+        ]);
+
+        let changes = before.diff(&after);
+        assert_eq!(vec![
+            ModelChange::ReturnTypeChanged {
+                method: "column".to_owned(), from: ReturnType::UnsignedSize, to: ReturnType::UnsignedInteger64 },
+            ModelChange::MethodAdded("enabled".to_owned()),
+        ], changes);
+        assert!(changes[0].is_breaking());
+        assert!(!changes[1].is_breaking());
+
+        assert!(before.diff(&before).is_empty());
+        assert_eq!(vec![
+            ModelChange::ReturnTypeChanged {
+                method: "column".to_owned(), from: ReturnType::UnsignedInteger64, to: ReturnType::UnsignedSize },
+            ModelChange::MethodRemoved("enabled".to_owned()),
+        ], after.diff(&before));
+    }
+
+    #[test]
+    fn test_enumtrait_validate_traitenum() {
+        let mut children_def = RelationDefinition::new(Identifier::new(vec![], "ChildTrait".to_owned()));
+        children_def.nature = Some(RelationNature::OneToMany);
+        let mut enabled_def = BoolDefinition::new();
+        enabled_def.default = Some(true);
+
+        let enumtrait = EnumTrait::new(Identifier::new(vec![], "MyTrait".to_owned()), vec![
+            Method::new("name".to_owned(), ReturnType::StaticStr, Definition::StaticStr(StaticStrDefinition::new())),
+            Method::new("column".to_owned(), ReturnType::UnsignedSize, Definition::UnsignedSize(NumberDefinition::new())),
+            Method::new("enabled".to_owned(), ReturnType::Bool, Definition::Bool(enabled_def)),
+            Method::new("children".to_owned(), ReturnType::BoxedTraitIterator, Definition::Relation(children_def)),
+        ]);
+
+        let values = |name: &str, column: Option<usize>| {
+            let mut values = HashMap::new();
+            values.insert("name".to_owned(), AttributeValue::new(Value::StaticStr(name.to_owned())));
+            if let Some(column) = column {
+                values.insert("column".to_owned(), AttributeValue::new(Value::UnsignedSize(column)));
+            }
+            values
+        };
+
+        let mut relation_enums = HashMap::new();
+        relation_enums.insert("children".to_owned(), Identifier::new(vec![], "ChildEnum".to_owned()));
+        let complete = TraitEnum::new(Identifier::new(vec![], "MyEnum".to_owned()), vec![
+            Variant::new("Alpha".to_owned(), 0, values("alpha", Some(0))),
+            Variant::new("Bravo".to_owned(), 1, values("bravo", Some(1))),
+        ], relation_enums);
+
+        assert_eq!(Ok(()), enumtrait.validate_traitenum(&complete),
+            "A complete traitenum should validate, with defaults filling in missing values");
+
+        let incomplete = TraitEnum::new(Identifier::new(vec![], "MyEnum".to_owned()), vec![
+            Variant::new("Alpha".to_owned(), 0, values("alpha", Some(0))),
+            Variant::new("Bravo".to_owned(), 1, values("bravo", None)),
+        ], HashMap::new());
+
+        assert_eq!(Err(vec![
+                "Missing value for attribute `column`: Bravo".to_owned(),
+                "Missing relation enum for relation: children".to_owned(),
+            ]), enumtrait.validate_traitenum(&incomplete),
+            "An incomplete traitenum should report each missing value");
+    }
+
+    #[test]
+    fn test_value_display() {
+        assert_eq!("\"alpha\"", Value::StaticStr("alpha".to_owned()).to_string());
+        assert_eq!("-5", Value::Integer64(-5).to_string());
+        assert_eq!("true", Value::Bool(true).to_string());
+
+        let variant_id = Identifier::new(vec!["crate".to_owned(), "MyEnum".to_owned()], "Alpha".to_owned());
+        assert_eq!("crate::MyEnum::Alpha", Value::EnumVariant(variant_id).to_string());
+    }
+}
@@ -197,6 +197,13 @@ impl DefinitionParser for EnumDefinitionParser {
 
 struct StrDefinitionParser{}
 
+impl StrDefinitionParser {
+    const DEFINITION_PREFIX: &'static str = "prefix";
+    const DEFINITION_SUFFIX: &'static str = "suffix";
+    const PRESET_PREFIXED: &'static str = "Prefixed";
+    const PRESET_SUFFIXED: &'static str = "Suffixed";
+}
+
 impl DefinitionParser for StrDefinitionParser {
     const NAME: &'static str = model::StaticStrDefinition::DEFINITION_NAME;
 
@@ -216,12 +223,30 @@ impl DefinitionParser for StrDefinitionParser {
             Self::DEFINITION_PRESET => {
                     let variant_ident = content.parse::<syn::Ident>()?;
                     let variant_name = variant_ident.to_string();
-                    let preset = model::StringPreset::from_str(&variant_name)
-                        .map_err(|_| {
-                            mksynerr!(&meta.path, "Unknown String preset: {}", variant_name)
-                        })?;
+                    let preset = if content.peek(syn::token::Paren) {
+                        // affix presets carry their text: Prefixed("ROLE_")
+                        let affix_content;
+                        syn::parenthesized!(affix_content in content);
+                        let affix = affix_content.parse::<syn::LitStr>()?.value();
+                        match variant_name.as_str() {
+                            Self::PRESET_PREFIXED => model::StringPreset::Prefixed(affix),
+                            Self::PRESET_SUFFIXED => model::StringPreset::Suffixed(affix),
+                            _ => synerr!(&variant_ident, "Unknown String preset: {}", variant_name)
+                        }
+                    } else {
+                        model::StringPreset::from_str(&variant_name)
+                            .map_err(|_| {
+                                mksynerr!(&meta.path, "Unknown String preset: {}", variant_name)
+                            })?
+                    };
                     strdef.preset = Some(preset);
             },
+            Self::DEFINITION_PREFIX => {
+                    strdef.prefix = Some(content.parse::<syn::LitStr>()?.value())
+            },
+            Self::DEFINITION_SUFFIX => {
+                    strdef.suffix = Some(content.parse::<syn::LitStr>()?.value())
+            },
             _ => return Self::err_unknown_setting(&meta.path, setting_name)
         }
 
@@ -257,6 +282,10 @@ impl DefinitionParser for RelDefinitionParser {
             },
             Self::DEFINITION_DISPATCH => {
                 let variant_ident = content.parse::<syn::Ident>()?;
+                if reldef.dispatch == Some(model::Dispatch::StaticSlice) {
+                    synerr!(variant_ident, "Dispatch is implied by a static slice return type and cannot be set");
+                }
+
                 let variant_name = variant_ident.to_string();
                 let dispatch = model::Dispatch::from_str(&variant_name)
                     .map_err(|_| mksynerr!(variant_ident, "Unknown dispatch: {}", variant_name) )?;
@@ -274,24 +303,32 @@ struct NumDefinitionParser{}
 impl NumDefinitionParser {
     const DEFINITION_START: &'static str = "start";
     const DEFINITION_INCREMENT: &'static str = "increment";
+    const DEFINITION_FACTOR: &'static str = "factor";
+    const DEFINITION_DESCENDING: &'static str = "descending";
+    const DEFINITION_MODULO: &'static str = "modulo";
 
     fn parse_number_definition<N>(
             def: &mut model::NumberDefinition<N>,
             meta: &ParseNestedMeta,
             setting_name: &str,
             content: syn::parse::ParseBuffer,
-            _return_type: model::ReturnType,
+            return_type: model::ReturnType,
             is_float: bool) -> Result<(), syn::Error>
     where
-        N: FromStr,
+        N: model::Number,
         N::Err: std::fmt::Display
     {
+        // integers are range-checked against the method's return type, naming the type and its bounds on failure
         macro_rules! parsenum {
             () => {
                 if is_float {
-                        content.parse::<syn::LitFloat>()?.base10_parse()?
+                        parse_float(&content)?
                 } else {
-                        content.parse::<syn::LitInt>()?.base10_parse()?
+                        let lit = content.parse::<syn::LitInt>()?;
+                        lit.base10_parse().map_err(|_| {
+                            mksynerr!(&lit, "Value `{}` is out of range for `{}` ({}..={}): {}",
+                                lit.base10_digits(), return_type, N::MIN, N::MAX, setting_name)
+                        })?
                 } 
             };
         }
@@ -318,6 +355,17 @@ impl NumDefinitionParser {
                     let n: N = parsenum!();
                     def.increment = Some(n)
             },
+            Self::DEFINITION_FACTOR => {
+                    let n: N = parsenum!();
+                    def.factor = Some(n)
+            },
+            Self::DEFINITION_DESCENDING => {
+                    def.descending = content.parse::<syn::LitBool>()?.value()
+            },
+            Self::DEFINITION_MODULO => {
+                    let n: N = parsenum!();
+                    def.modulo = Some(n)
+            },
             _ => return Self::err_unknown_setting(&meta.path, setting_name.to_owned())
         }
 
@@ -341,8 +389,10 @@ impl DefinitionParser for NumDefinitionParser {
             model::Definition::Integer64(def) => Self::parse_number_definition(def, meta, &setting_name, content, return_type, false),
             model::Definition::Float64(def) => Self::parse_number_definition(def, meta, &setting_name, content, return_type, true),
             model::Definition::UnsignedInteger32(def) => Self::parse_number_definition(def, meta, &setting_name, content, return_type, false),
-            model::Definition::Integer32(def) => Self::parse_number_definition(def, meta, &setting_name, content, return_type, true),
-            model::Definition::Float32(def) => Self::parse_number_definition(def, meta, &setting_name, content, return_type, false),
+            model::Definition::Integer32(def) => Self::parse_number_definition(def, meta, &setting_name, content, return_type, false),
+            model::Definition::Float32(def) => Self::parse_number_definition(def, meta, &setting_name, content, return_type, true),
+            // bytes use plain integer literals for settings, which are range-checked when parsed into a u8
+            model::Definition::Byte(def) => Self::parse_number_definition(def, meta, &setting_name, content, return_type, false),
             _ => unreachable!("Unexpected Num definition associated data for setting: {}", setting_name)
         }
     }
@@ -364,7 +414,7 @@ pub(crate) fn parse_variant(variant_name: &str, attr: &syn::Attribute, model: &m
             synerr!(&meta.path, "Duplicate enum attribute value for: {}", attr_name);
         }
 
-        let method = model.methods().iter().find(|m| m.name() == attr_name)
+        let method = model.method(&attr_name)
             .ok_or_else(|| {
                 mksynerr!(&meta.path, "Unknown enum attribute: {}", attr_name)
             })?;
@@ -374,43 +424,7 @@ pub(crate) fn parse_variant(variant_name: &str, attr: &syn::Attribute, model: &m
         let content;
         syn::parenthesized!(content in meta.input);
 
-        let value = match attribute_def {
-            model::Definition::Bool(_) => model::Value::Bool(
-                content.parse::<syn::LitBool>()?.value()),
-            model::Definition::StaticStr(_) => model::Value::StaticStr(
-                content.parse::<syn::LitStr>()?.value()),
-            model::Definition::UnsignedSize(_) => model::Value::UnsignedSize(
-                content.parse::<syn::LitInt>()?.base10_parse()?),
-            model::Definition::UnsignedInteger64(_) => model::Value::UnsignedInteger64(
-                content.parse::<syn::LitInt>()?.base10_parse()?),
-            model::Definition::Integer64(_) => model::Value::Integer64(
-                content.parse::<syn::LitInt>()?.base10_parse()?),
-            model::Definition::Float64(_) => model::Value::Float64(
-                content.parse::<syn::LitFloat>()?.base10_parse()?),
-            model::Definition::UnsignedInteger32(_) => model::Value::UnsignedInteger32(
-                content.parse::<syn::LitInt>()?.base10_parse()?),
-            model::Definition::Integer32(_) => model::Value::Integer32(
-                content.parse::<syn::LitInt>()?.base10_parse()?),
-            model::Definition::Float32(_) => model::Value::Float32(
-                content.parse::<syn::LitFloat>()?.base10_parse()?),
-            model::Definition::Byte(_) => model::Value::Byte(
-                content.parse::<syn::LitByte>()?.value()),
-            model::Definition::FieldlessEnum(enumdef) => {
-                let mut id = content.parse::<model::Identifier>()?;
-                // users are allowed to drop the enum type in short-hand (Foo instead of MyEnum::Foo)
-                // fill in the path if they do this
-                if id.path().is_empty() {
-                    id = enumdef.identifier.append(id)
-                }
-
-                model::Value::EnumVariant(id)
-            },
-            model::Definition::Relation(_) => model::Value::Relation(
-                content.parse::<model::Identifier>()?),
-            model::Definition::Type(_) => model::Value::Type(
-                content.parse::<model::Identifier>()?),
-        };
-
+        let value = parse_value(&attr_name, attribute_def, &content)?;
         let attribute_value = model::AttributeValue::new(value);
         variant_build.value(attr_name, attribute_value);
 
@@ -420,6 +434,90 @@ pub(crate) fn parse_variant(variant_name: &str, attr: &syn::Attribute, model: &m
     Ok(variant_build)
 }
 
+/// Parses an attribute value, as expected by the attribute definition of its trait method
+pub(crate) fn parse_value(attr_name: &str, attribute_def: &model::Definition, content: syn::parse::ParseStream)
+        -> Result<model::Value, syn::Error> {
+    let value = match attribute_def {
+        model::Definition::Bool(_) => model::Value::Bool(
+            content.parse::<syn::LitBool>()?.value()),
+        model::Definition::StaticStr(_) => model::Value::StaticStr(
+            content.parse::<syn::LitStr>()?.value()),
+        model::Definition::UnsignedSize(_) => model::Value::UnsignedSize(
+            content.parse::<syn::LitInt>()?.base10_parse()?),
+        model::Definition::UnsignedInteger64(_) => model::Value::UnsignedInteger64(
+            content.parse::<syn::LitInt>()?.base10_parse()?),
+        model::Definition::Integer64(_) => model::Value::Integer64(
+            content.parse::<syn::LitInt>()?.base10_parse()?),
+        model::Definition::Float64(_) => model::Value::Float64(parse_float(content)?),
+        model::Definition::UnsignedInteger32(_) => model::Value::UnsignedInteger32(
+            content.parse::<syn::LitInt>()?.base10_parse()?),
+        model::Definition::Integer32(_) => model::Value::Integer32(
+            content.parse::<syn::LitInt>()?.base10_parse()?),
+        model::Definition::Float32(_) => model::Value::Float32(parse_float(content)?),
+        // bytes accept either a byte literal (b'x') or a plain integer literal (0-255)
+        model::Definition::Byte(_) => model::Value::Byte(
+            match content.parse::<syn::Lit>()? {
+                syn::Lit::Byte(lit) => lit.value(),
+                syn::Lit::Int(lit) => lit.base10_parse()?,
+                lit => synerr!(lit, "Expected a byte or integer literal for attribute: {}", attr_name)
+            }),
+        model::Definition::FieldlessEnum(enumdef) => {
+            let mut id = content.parse::<model::Identifier>()?;
+            // users are allowed to drop the enum type in short-hand (Foo instead of MyEnum::Foo)
+            // fill in the path if they do this
+            if id.path().is_empty() {
+                id = enumdef.identifier.append(id)
+            }
+
+            model::Value::EnumVariant(id)
+        },
+        model::Definition::Relation(_) => model::Value::Relation(
+            content.parse::<model::Identifier>()?),
+        model::Definition::Type(_) => model::Value::Type(
+            content.parse::<model::Identifier>()?),
+    };
+
+    Ok(value)
+}
+
+/// Parses a float literal, or a path to a special value that has no literal form: NAN, INFINITY, NEG_INFINITY
+fn parse_float<N>(content: syn::parse::ParseStream) -> Result<N, syn::Error>
+where
+    N: model::Number,
+    N::Err: std::fmt::Display
+{
+    if content.peek(syn::LitFloat) {
+        return content.parse::<syn::LitFloat>()?.base10_parse();
+    }
+
+    let path = content.parse::<syn::Path>()?;
+    let path_str = path.segments.iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::");
+
+    N::from_constant(&path_str)
+        .ok_or_else(|| mksynerr!(&path, "Unsupported float constant: {}", path_str))
+}
+
+// non-finite floats have no literal form, so they're written as their associated constant
+macro_rules! float_tokens {
+    ($float_type:ident, $n:expr) => {
+        {
+            let n = $n;
+            if n.is_nan() {
+                quote::quote!(::core::$float_type::NAN)
+            } else if n == $float_type::INFINITY {
+                quote::quote!(::core::$float_type::INFINITY)
+            } else if n == $float_type::NEG_INFINITY {
+                quote::quote!(::core::$float_type::NEG_INFINITY)
+            } else {
+                quote::quote!(#n)
+            }
+        }
+    };
+}
+
 impl quote::ToTokens for model::AttributeValue {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         tokens.append_all(
@@ -429,10 +527,10 @@ impl quote::ToTokens for model::AttributeValue {
                 model::Value::UnsignedSize(n) => quote::quote!(#n),
                 model::Value::UnsignedInteger64(n) => quote::quote!(#n),
                 model::Value::Integer64(n) => quote::quote!(#n),
-                model::Value::Float64(n) => quote::quote!(#n),
+                model::Value::Float64(n) => float_tokens!(f64, *n),
                 model::Value::UnsignedInteger32(n) => quote::quote!(#n),
                 model::Value::Integer32(n) => quote::quote!(#n),
-                model::Value::Float32(n) => quote::quote!(#n),
+                model::Value::Float32(n) => float_tokens!(f32, *n),
                 model::Value::Byte(n) => quote::quote!(#n),
                 model::Value::EnumVariant(id) => id.to_token_stream(),
                 model::Value::Relation(id) => id.to_token_stream(),
@@ -474,7 +572,7 @@ impl From<&model::Identifier> for syn::Path {
 /// Using this with the following return types will panic!():
 ///   - ReturnType::BoxedTrait
 ///   - ReturnType::BoxedTraitIterator
-///   - ReturnType::AssociatedType
+///   - ReturnType::StaticSlice
 ///   - ReturnType::Type
 impl quote::ToTokens for model::ReturnType{
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
@@ -493,7 +591,7 @@ impl quote::ToTokens for model::ReturnType{
                 // this has to be handled conditionally
                 model::ReturnType::BoxedTrait => unreachable!("ReturnType::BoxedTrait cannot directly produce a TokenStream"),
                 model::ReturnType::BoxedTraitIterator => unreachable!("ReturnType::BoxedTraitIterator cannot directly produce a TokenStream"),
-                model::ReturnType::AssociatedType => unreachable!("ReturnType::AssociatedType cannot directly produce a TokenStream"),
+                model::ReturnType::StaticSlice => unreachable!("ReturnType::StaticSlice cannot directly produce a TokenStream"),
                 model::ReturnType::Enum => unreachable!("ReturnType::Enum cannot directly produce a TokenStream"),
                 model::ReturnType::Type => unreachable!("ReturnType::Type cannot directly produce a TokenStream")
             }
@@ -530,6 +628,16 @@ impl model::Method {
                     ::std::boxed::Box<dyn ::std::iter::Iterator<Item = ::std::boxed::Box<dyn #ident>>>
                 }
             },
+            model::ReturnType::StaticSlice => {
+                let ident = self.attribute_definition()
+                    .get_relation_definition()
+                    .identifier()
+                    .to_token_stream();
+
+                quote::quote!{
+                    &'static [#ident]
+                }
+            },
             model::ReturnType::Type => {
                 match self.attribute_definition() {
                     model::Definition::FieldlessEnum(enumdef) => enumdef.identifier.to_token_stream(),
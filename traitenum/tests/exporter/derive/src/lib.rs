@@ -1,5 +1,10 @@
-traitenum_lib::gen_require!(traitenum_test_exporter, traitenum_test_exporter_derive);
-
-traitenum_lib::gen_derive_macro!(SimpleTraitEnum, derive_traitenum_simple, traitlib::TRAITENUM_MODEL_BYTES_SIMPLE_TRAIT);
-traitenum_lib::gen_derive_macro!(ChildTraitEnum, derive_traitenum_child, traitlib::TRAITENUM_MODEL_BYTES_CHILD_TRAIT);
-traitenum_lib::gen_derive_macro!(ParentTraitEnum, derive_traitenum_parent, traitlib::TRAITENUM_MODEL_BYTES_PARENT_TRAIT);
\ No newline at end of file
+traitenum_lib::gen_derive_crate!(traitenum_test_exporter, traitenum_test_exporter_derive, [
+    (SimpleTraitEnum, derive_traitenum_simple, TRAITENUM_MODEL_BYTES_SIMPLE_TRAIT),
+    (ChildTraitEnum, derive_traitenum_child, TRAITENUM_MODEL_BYTES_CHILD_TRAIT),
+    (ParentTraitEnum, derive_traitenum_parent, TRAITENUM_MODEL_BYTES_PARENT_TRAIT),
+    (StaticParentTraitEnum, derive_traitenum_static_parent, TRAITENUM_MODEL_BYTES_STATIC_PARENT_TRAIT),
+    (QualifiedSimpleEnum, derive_traitenum_qualified_simple, TRAITENUM_MODEL_BYTES_SIMPLE_TRAIT,
+        ::traitenum_test_exporter::SimpleTrait),
+    (QualifiedChildEnum, derive_traitenum_qualified_child, TRAITENUM_MODEL_BYTES_CHILD_TRAIT,
+        ::traitenum_test_exporter::ChildTrait),
+]);
\ No newline at end of file
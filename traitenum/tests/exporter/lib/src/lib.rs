@@ -24,6 +24,26 @@ pub trait ChildTrait {
     fn parent(&self) -> Box<dyn ParentTrait>;
 }
 
+/// Related by slice, rather than by boxed iterator. A derived enum would opt in to `VARIANTS` instead.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StaticChildEnum {
+    Zero,
+    One,
+    Two
+}
+
+impl StaticChildEnum {
+    pub const VARIANTS: &'static [Self] = &[Self::Zero, Self::One, Self::Two];
+}
+
+#[enumtrait]
+pub trait StaticParentTrait {
+    #[enumtrait::Str(preset(Variant))]
+    fn name(&self) -> &'static str;
+
+    fn children(&self) -> &'static [StaticChildEnum];
+}
+
 #[cfg(test)]
 mod tests {
     use traitenum_lib;
@@ -34,4 +54,15 @@ mod tests {
         let bytes = crate::simple_trait::TRAITENUM_MODEL_BYTES_SIMPLE_TRAIT;
         let _model: traitenum_lib::model::EnumTrait = bincode::deserialize(bytes).unwrap();
     }
+
+    #[test]
+    fn test_relation_target_trait() {
+        let parent_model = traitenum_lib::model::EnumTrait::deserialize(crate::TRAITENUM_MODEL_BYTES_PARENT_TRAIT).unwrap();
+        let child_model = traitenum_lib::model::EnumTrait::deserialize(crate::TRAITENUM_MODEL_BYTES_CHILD_TRAIT).unwrap();
+
+        assert_eq!("ChildTrait", parent_model.relation_target_trait("children").unwrap().name());
+        assert_eq!("ParentTrait", child_model.relation_target_trait("parent").unwrap().name());
+        assert!(parent_model.relation_target_trait("name").is_none());
+        assert!(child_model.relation_target_trait("missing").is_none());
+    }
 }
@@ -1,6 +1,6 @@
 use traitenum::enumtrait;
 
-#[enumtrait]
+#[enumtrait(display_from = "name")]
 pub trait SimpleTrait {
     #[enumtrait::Str(default("spunko"))]
     fn name(&self) -> &'static str;
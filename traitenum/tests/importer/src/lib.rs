@@ -2,9 +2,13 @@ use traitenum_test_exporter_derive as exporter_derive;
 use traitenum_test_exporter::SimpleTrait;
 use traitenum_test_exporter::ParentTrait;
 use traitenum_test_exporter::ChildTrait;
+use traitenum_test_exporter::{StaticParentTrait, StaticChildEnum};
 
 #[derive(exporter_derive::SimpleTraitEnum)]
 //#[traitenum::implements(SimpleTrait)]
+#[traitenum(try_from = "name")]
+#[traitenum(enumtrait_model)]
+#[traitenum(variants)]
 pub enum ImporterEnum {
     #[traitenum(name("alpha"), column(0))]
     Alpha,
@@ -28,14 +32,64 @@ pub enum ImporterParentEnum {
 #[derive(exporter_derive::ChildTraitEnum)]
 #[traitenum(parent(ImporterParentEnum::Bravo))]
 pub enum ImporterChildAlphaEnum {
+    #[traitenum(parent(ImporterParentEnum::Alpha))]
     Zero,
     One,
     Two,
 }
 
+#[derive(exporter_derive::StaticParentTraitEnum)]
+pub enum ImporterStaticParentEnum {
+    #[traitenum(children(StaticChildEnum))]
+    Alpha,
+    #[traitenum(children(StaticChildEnum))]
+    Bravo
+}
+
+/// The enum's derive is named apart from its trait, which is implemented by path despite a local namesake
+pub mod qualified {
+    pub trait SimpleTrait {}
+
+    #[derive(traitenum_test_exporter_derive::QualifiedSimpleEnum)]
+    pub enum QualifiedEnum {
+        #[traitenum(column(0))]
+        Alpha,
+        #[traitenum(name("bravo"), column(1))]
+        Bravo
+    }
+
+    // without opting in, the enum's own items don't collide with the derived `VARIANTS` and `variants()`
+    impl QualifiedEnum {
+        pub const VARIANTS: usize = 2;
+    }
+
+    pub trait ChildTrait {}
+
+    use traitenum_test_exporter::ParentTrait;
+
+    #[derive(traitenum_test_exporter_derive::QualifiedChildEnum)]
+    #[traitenum(parent(parent::QualifiedParentEnum::Alpha))]
+    pub enum QualifiedChildEnum {
+        Zero,
+        One
+    }
+
+    /// Iterates the boxed children, which must implement the exporter's trait rather than the local namesake
+    pub mod parent {
+        use traitenum_test_exporter::{ParentTrait, ChildTrait};
+        use super::{QualifiedChildEnum, QualifiedChildEnumBoxedIterator};
+
+        #[derive(traitenum_test_exporter_derive::ParentTraitEnum)]
+        pub enum QualifiedParentEnum {
+            #[traitenum(children(QualifiedChildEnum))]
+            Alpha
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use traitenum_test_exporter::{SimpleTrait,ChildTrait,ParentTrait};
+    use traitenum_test_exporter::{SimpleTrait,ChildTrait,ParentTrait,StaticParentTrait};
 
     #[test]
     fn test_enum_attributes() {
@@ -59,9 +113,96 @@ mod tests {
         assert_eq!("One", super::ImporterParentEnum::Alpha.children().collect::<Vec<_>>()[1].topic());
     }
 
+    #[test]
+    fn test_enum_typed_iterators() {
+        let children: Vec<super::ImporterChildAlphaEnum> = super::ImporterParentEnum::Alpha.children_typed().collect();
+        assert!(matches!(children[..], [
+            super::ImporterChildAlphaEnum::Zero,
+            super::ImporterChildAlphaEnum::One,
+            super::ImporterChildAlphaEnum::Two]));
+        assert_eq!(2, children[2].ordinal());
+    }
+
+    #[test]
+    fn test_enum_variants() {
+        assert_eq!(3, super::ImporterChildAlphaEnum::VARIANTS.len());
+        let topics: Vec<&str> = super::ImporterChildAlphaEnum::VARIANTS.iter().map(|child| child.topic()).collect();
+        assert_eq!(vec!["Zero", "One", "Two"], topics);
+
+        // should iterate in declaration order, matching the const slice
+        let variants: Vec<super::ImporterEnum> = super::ImporterEnum::variants().collect();
+        assert!(matches!(variants[..], [
+            super::ImporterEnum::Alpha,
+            super::ImporterEnum::Bravo,
+            super::ImporterEnum::Charlie]));
+        assert_eq!(super::ImporterEnum::VARIANTS.len(), super::ImporterEnum::variants().count());
+        let ordinals: Vec<usize> = super::ImporterChildAlphaEnum::variants().map(|child| child.ordinal()).collect();
+        assert_eq!(vec![0, 1, 2], ordinals);
+    }
+
+    #[test]
+    fn test_enum_static_slice() {
+        let children: &'static [super::StaticChildEnum] = super::ImporterStaticParentEnum::Bravo.children();
+        // the related enum's variants are iterated in place, without allocating or boxing
+        let mut ordinal = 0;
+        for child in children {
+            assert_eq!(super::StaticChildEnum::VARIANTS[ordinal], *child);
+            ordinal += 1;
+        }
+
+        assert_eq!(3, ordinal);
+        assert!(matches!(children, [
+            super::StaticChildEnum::Zero,
+            super::StaticChildEnum::One,
+            super::StaticChildEnum::Two]));
+        assert_eq!(super::StaticChildEnum::One, super::ImporterStaticParentEnum::Alpha.children()[1]);
+
+        assert_eq!(2, super::qualified::QualifiedEnum::VARIANTS,
+            "An enum that doesn't opt in should keep its own `VARIANTS`");
+    }
+
     #[test]
     fn test_enum_many_to_one() {
         assert_eq!("Bravo", super::ImporterChildAlphaEnum::Two.parent().name());
+        // a variant may override the enum-level parent
+        assert_eq!("Alpha", super::ImporterChildAlphaEnum::Zero.parent().name());
+    }
+
+    #[test]
+    fn test_display_from() {
+        assert_eq!(super::ImporterEnum::Alpha.name(), format!("{}", super::ImporterEnum::Alpha));
+        assert_eq!("spunko", super::ImporterEnum::Bravo.to_string());
+    }
+
+    #[test]
+    fn test_try_from_name() {
+        assert!(matches!(super::ImporterEnum::try_from("alpha"), Ok(super::ImporterEnum::Alpha)));
+
+        let err: super::ImporterEnumTryFromError = super::ImporterEnum::try_from("delta").err().unwrap();
+        assert_eq!(&["alpha", "spunko", "charles"], err.valid_names);
+        assert_eq!("Unknown variant name `delta`. Expected one of: alpha, spunko, charles", err.to_string());
+    }
+
+    #[test]
+    fn test_enumtrait_model() {
+        let model = super::ImporterEnum::enumtrait_model();
+        assert_eq!("SimpleTrait", model.identifier().name());
+        assert_eq!(2, model.methods().len());
+    }
+
+    #[test]
+    fn test_qualified_trait_path() {
+        assert_eq!("spunko", super::qualified::QualifiedEnum::Alpha.name());
+        assert_eq!("bravo :: 1", super::qualified::QualifiedEnum::Bravo.default_impl());
+    }
+
+    #[test]
+    fn test_qualified_trait_path_relation() {
+        let topics: Vec<&str> = super::qualified::parent::QualifiedParentEnum::Alpha.children()
+            .map(|child| child.topic())
+            .collect();
+        assert_eq!(vec!["Zero", "One"], topics);
+        assert_eq!("Alpha", super::qualified::QualifiedChildEnum::One.parent().name());
     }
 
     #[test]